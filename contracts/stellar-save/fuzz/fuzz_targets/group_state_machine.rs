@@ -0,0 +1,13 @@
+//! Feeds arbitrary call sequences to the contract. `Harness::apply` checks
+//! the group invariants after every call.
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use stellar_save::state_machine::{Harness, Op};
+
+fuzz_target!(|ops: Vec<Op>| {
+    let mut harness = Harness::new();
+    for op in &ops {
+        harness.apply(op);
+    }
+});
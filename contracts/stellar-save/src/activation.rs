@@ -0,0 +1,70 @@
+use soroban_sdk::contracttype;
+
+/// Controls how a Pending group becomes Active without the creator having to
+/// activate it by hand.
+///
+/// Both rules are optional and can be combined:
+/// - `activate_when_full`: the group activates as soon as the last seat
+///   (`max_members`) is filled.
+/// - `start_at`: once this time passes, anyone can call `process_activation`.
+///   The group activates if `min_members` have joined, otherwise it is
+///   cancelled and members are refunded.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ActivationPolicy {
+    /// Activate automatically when `max_members` is reached.
+    pub activate_when_full: bool,
+
+    /// Scheduled start time (Unix timestamp in seconds).
+    /// A value of 0 means the group has no scheduled start.
+    pub start_at: u64,
+}
+
+impl ActivationPolicy {
+    /// Policy with no automatic activation; the creator activates manually.
+    pub fn manual() -> Self {
+        Self {
+            activate_when_full: false,
+            start_at: 0,
+        }
+    }
+
+    /// Returns true if the policy has a scheduled start time.
+    pub fn has_scheduled_start(&self) -> bool {
+        self.start_at != 0
+    }
+
+    /// Returns true if the scheduled start time has been reached.
+    /// Always false when no start time is set.
+    pub fn is_start_due(&self, current_time: u64) -> bool {
+        self.has_scheduled_start() && current_time >= self.start_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manual_policy() {
+        let policy = ActivationPolicy::default();
+
+        assert_eq!(policy, ActivationPolicy::manual());
+        assert!(!policy.activate_when_full);
+        assert!(!policy.has_scheduled_start());
+        assert!(!policy.is_start_due(u64::MAX));
+    }
+
+    #[test]
+    fn test_scheduled_start_due() {
+        let policy = ActivationPolicy {
+            activate_when_full: false,
+            start_at: 1000,
+        };
+
+        assert!(policy.has_scheduled_start());
+        assert!(!policy.is_start_due(999));
+        assert!(policy.is_start_due(1000));
+        assert!(policy.is_start_due(1001));
+    }
+}
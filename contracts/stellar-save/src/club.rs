@@ -0,0 +1,108 @@
+use soroban_sdk::{contracttype, Address};
+
+use crate::destination::PAYOUT_BPS_TOTAL;
+
+/// Turns a group into an accumulating savings club instead of a rotating
+/// savings group.
+///
+/// Members still contribute every cycle, but nobody receives the pool.
+/// Each member's contributions build up a personal balance that they
+/// withdraw at `maturity_at`, or earlier at the cost of a penalty. Penalties
+/// stay in the club and are shared equally by the members who save until
+/// maturity.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SavingsClubConfig {
+    /// When balances can be withdrawn without penalty (Unix timestamp in
+    /// seconds). The club only runs the cycles that end by this time.
+    pub maturity_at: u64,
+
+    /// Share of the balance forfeited on early withdrawal, in basis points.
+    pub early_withdrawal_penalty_bps: u32,
+
+    /// Amount the club saves toward together, or 0 for no shared goal.
+    pub shared_goal: i128,
+}
+
+impl SavingsClubConfig {
+    /// Checks that maturity lies after `now`, the penalty is at most 100%
+    /// and the shared goal is not negative.
+    pub fn is_valid(&self, now: u64) -> bool {
+        self.maturity_at > now
+            && self.early_withdrawal_penalty_bps <= PAYOUT_BPS_TOTAL
+            && self.shared_goal >= 0
+    }
+
+    /// Returns true once balances can be withdrawn without penalty.
+    pub fn is_mature(&self, now: u64) -> bool {
+        now >= self.maturity_at
+    }
+
+    /// Returns the penalty for withdrawing `balance` before maturity,
+    /// rounded down.
+    pub fn early_withdrawal_penalty(&self, balance: i128) -> i128 {
+        balance * self.early_withdrawal_penalty_bps as i128 / PAYOUT_BPS_TOTAL as i128
+    }
+}
+
+/// A member's withdrawal of their savings from a club.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SavingsWithdrawal {
+    /// ID of the club.
+    pub group_id: u64,
+
+    /// Member who withdrew.
+    pub member: Address,
+
+    /// Member's saved balance at withdrawal, in stroops.
+    pub balance: i128,
+
+    /// Amount forfeited for withdrawing before maturity.
+    pub penalty: i128,
+
+    /// Member's share of earlier members' penalties, paid at maturity.
+    pub bonus: i128,
+
+    /// Amount paid out: balance - penalty + bonus.
+    pub amount: i128,
+
+    /// When the withdrawal was made.
+    pub withdrawn_at: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(penalty_bps: u32) -> SavingsClubConfig {
+        SavingsClubConfig {
+            maturity_at: 10_000,
+            early_withdrawal_penalty_bps: penalty_bps,
+            shared_goal: 0,
+        }
+    }
+
+    #[test]
+    fn test_config_validation() {
+        assert!(config(1_000).is_valid(0));
+        assert!(!config(1_000).is_valid(10_000));
+        assert!(!config(PAYOUT_BPS_TOTAL + 1).is_valid(0));
+
+        let negative_goal = SavingsClubConfig {
+            shared_goal: -1,
+            ..config(0)
+        };
+        assert!(!negative_goal.is_valid(0));
+    }
+
+    #[test]
+    fn test_maturity_and_penalty() {
+        let club = config(1_000);
+
+        assert!(!club.is_mature(9_999));
+        assert!(club.is_mature(10_000));
+        assert_eq!(club.early_withdrawal_penalty(1_005), 100);
+        assert_eq!(config(0).early_withdrawal_penalty(1_005), 0);
+    }
+}
@@ -8,10 +8,14 @@ use soroban_sdk::{contracttype, Address};
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ContributionRecord {
-    /// Address of the member making the contribution.
-    /// Used to identify who contributed and verify authorization.
+    /// Address of the member the contribution is credited to.
+    /// Used to identify whose obligation for the cycle is satisfied.
     pub member_address: Address,
 
+    /// Address whose funds paid for the contribution.
+    /// Same as `member_address` unless someone paid on the member's behalf.
+    pub payer: Address,
+
     /// ID of the group this contribution belongs to.
     /// Links the contribution to a specific savings group.
     pub group_id: u64,
@@ -32,6 +36,8 @@ pub struct ContributionRecord {
 
 impl ContributionRecord {
     /// Creates a new ContributionRecord with validation.
+    /// The member is recorded as the payer; use `with_payer` for sponsored
+    /// contributions.
     ///
     /// # Arguments
     /// * `member_address` - Address of the contributing member
@@ -54,6 +60,7 @@ impl ContributionRecord {
         assert!(amount > 0, "amount must be greater than 0");
 
         Self {
+            payer: member_address.clone(),
             member_address,
             group_id,
             cycle_number,
@@ -62,6 +69,17 @@ impl ContributionRecord {
         }
     }
 
+    /// Records that `payer` paid this contribution on the member's behalf.
+    pub fn with_payer(mut self, payer: Address) -> Self {
+        self.payer = payer;
+        self
+    }
+
+    /// Returns true if someone other than the member paid this contribution.
+    pub fn is_sponsored(&self) -> bool {
+        self.payer != self.member_address
+    }
+
     /// Validates that the contribution record is sound.
     /// Returns true if all constraints are met.
     pub fn validate(&self) -> bool {
@@ -111,6 +129,24 @@ mod tests {
         assert_eq!(contribution.timestamp, 1234567890);
     }
 
+    #[test]
+    fn test_sponsored_contribution() {
+        let env = Env::default();
+        let member = Address::generate(&env);
+        let relative = Address::generate(&env);
+
+        let own = ContributionRecord::new(member.clone(), 1, 0, 10_000_000, 1234567890);
+        assert_eq!(own.payer, member);
+        assert!(!own.is_sponsored());
+
+        let sponsored = ContributionRecord::new(member.clone(), 1, 0, 10_000_000, 1234567890)
+            .with_payer(relative.clone());
+        assert_eq!(sponsored.member_address, member);
+        assert_eq!(sponsored.payer, relative);
+        assert!(sponsored.is_sponsored());
+        assert!(sponsored.is_from_member(&member));
+    }
+
     #[test]
     #[should_panic(expected = "amount must be greater than 0")]
     fn test_invalid_amount() {
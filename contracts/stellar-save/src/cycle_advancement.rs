@@ -2,7 +2,7 @@ use soroban_sdk::{Address, Env};
 use crate::{
     error::StellarSaveError,
     events::EventEmitter,
-    group::Group,
+    group::{Group, GroupStatus},
     storage::StorageKeyBuilder,
 };
 
@@ -53,15 +53,13 @@ pub fn advance_group_to_next_cycle(
     // Task 4: Emit event
     // Emit GroupStatusChanged event when transitioning to Completed state
     if group.is_complete() {
-        let old_status = (group.current_cycle - 1) as u32;
-        let new_status = 3u32; // Completed status code
         let timestamp = env.ledger().timestamp();
 
         EventEmitter::emit_group_status_changed(
             env,
             group_id,
-            old_status,
-            new_status,
+            GroupStatus::Active.as_u32(),
+            GroupStatus::Completed.as_u32(),
             caller.clone(),
             timestamp,
         );
@@ -110,8 +108,12 @@ mod tests {
             10_000_000, // 1 XLM
             604800,     // 1 week
             3,          // 3 members
+            2,          // 2 to start
             1234567890,
         );
+        group.add_member();
+        group.add_member();
+        group.activate(1234567890);
 
         assert_eq!(group.current_cycle, 0);
         assert!(group.is_active);
@@ -134,6 +136,7 @@ mod tests {
             10_000_000,
             604800,
             3,
+            2,
             1234567890,
         );
 
@@ -160,6 +163,7 @@ mod tests {
             10_000_000,
             604800,
             2,
+            2,
             1234567890,
         );
 
@@ -184,6 +188,7 @@ mod tests {
             10_000_000,
             604800,
             2,
+            2,
             1234567890,
         );
 
@@ -208,8 +213,12 @@ mod tests {
             10_000_000,
             604800,
             5,
+            2,
             1234567890,
         );
+        group.add_member();
+        group.add_member();
+        group.activate(1234567890);
 
         // Advance from cycle 0 to 1 (not completion)
         let result = advance_group_cycle_logic(&mut group);
@@ -234,6 +243,7 @@ mod tests {
             original_contribution,
             original_cycle_duration,
             original_max_members,
+            2,
             1234567890,
         );
 
@@ -251,7 +261,7 @@ mod tests {
         let env = Env::default();
         let creator = Address::generate(&env);
 
-        let mut group = Group::new(1, creator, 10_000_000, 604800, 4, 1234567890);
+        let mut group = Group::new(1, creator, 10_000_000, 604800, 4, 2, 1234567890);
 
         // Verify cycle progression
         assert_eq!(group.current_cycle, 0);
@@ -279,7 +289,7 @@ mod tests {
         let env = Env::default();
         let creator = Address::generate(&env);
 
-        let mut group = Group::new(1, creator, 10_000_000, 604800, 2, 1234567890);
+        let mut group = Group::new(1, creator, 10_000_000, 604800, 2, 2, 1234567890);
         group.current_cycle = 2; // Already complete
 
         let result = advance_group_cycle_logic(&mut group);
@@ -0,0 +1,192 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+/// Basis points representing the whole payout.
+pub const PAYOUT_BPS_TOTAL: u32 = 10_000;
+
+/// Maximum number of addresses a payout can be split across.
+pub const MAX_PAYOUT_SPLITS: u32 = 5;
+
+/// One share of a member's payout.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutSplit {
+    /// Address receiving this share.
+    pub destination: Address,
+
+    /// Share of the payout in basis points (10000 = 100%).
+    pub bps: u32,
+}
+
+/// Amount actually sent to one destination when a payout is executed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutTransfer {
+    /// Address that received the funds.
+    pub destination: Address,
+
+    /// Amount sent, in stroops.
+    pub amount: i128,
+}
+
+/// Where a member's payout should be sent instead of the member's own address.
+///
+/// A destination can be a single address (a landlord, a school, a cold
+/// wallet) or a split across up to `MAX_PAYOUT_SPLITS` addresses whose shares
+/// add up to exactly 10000 basis points.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutDestination {
+    /// Shares of the payout, in the order they are paid.
+    pub splits: Vec<PayoutSplit>,
+}
+
+impl PayoutDestination {
+    /// Sends the whole payout to a single address.
+    pub fn single(env: &Env, destination: Address) -> Self {
+        let mut splits = Vec::new(env);
+        splits.push_back(PayoutSplit {
+            destination,
+            bps: PAYOUT_BPS_TOTAL,
+        });
+        Self { splits }
+    }
+
+    /// Checks that there are 1 to `MAX_PAYOUT_SPLITS` shares, each share is
+    /// non-zero, no address appears twice, and the shares add up to 100%.
+    pub fn is_valid(&self) -> bool {
+        if self.splits.is_empty() || self.splits.len() > MAX_PAYOUT_SPLITS {
+            return false;
+        }
+
+        let mut total: u32 = 0;
+        for (idx, split) in self.splits.iter().enumerate() {
+            if split.bps == 0 {
+                return false;
+            }
+            if self
+                .splits
+                .iter()
+                .position(|other| other.destination == split.destination)
+                != Some(idx)
+            {
+                return false;
+            }
+            total = match total.checked_add(split.bps) {
+                Some(total) => total,
+                None => return false,
+            };
+        }
+
+        total == PAYOUT_BPS_TOTAL
+    }
+
+    /// Splits `amount` across the destinations.
+    ///
+    /// Each share is rounded down; the rounding remainder goes to the first
+    /// destination so the transfers always add up to `amount`.
+    pub fn allocate(&self, env: &Env, amount: i128) -> Vec<PayoutTransfer> {
+        let mut transfers = Vec::new(env);
+        let mut allocated: i128 = 0;
+
+        for split in self.splits.iter() {
+            let share = amount * split.bps as i128 / PAYOUT_BPS_TOTAL as i128;
+            allocated += share;
+            transfers.push_back(PayoutTransfer {
+                destination: split.destination,
+                amount: share,
+            });
+        }
+
+        if let Some(mut first) = transfers.get(0) {
+            first.amount += amount - allocated;
+            transfers.set(0, first);
+        }
+
+        transfers
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    fn split(env: &Env, bps: u32) -> PayoutSplit {
+        PayoutSplit {
+            destination: Address::generate(env),
+            bps,
+        }
+    }
+
+    #[test]
+    fn test_single_destination() {
+        let env = Env::default();
+        let landlord = Address::generate(&env);
+        let destination = PayoutDestination::single(&env, landlord.clone());
+
+        assert!(destination.is_valid());
+
+        let transfers = destination.allocate(&env, 1_000);
+        assert_eq!(transfers.len(), 1);
+        assert_eq!(
+            transfers.get(0).unwrap(),
+            PayoutTransfer {
+                destination: landlord,
+                amount: 1_000
+            }
+        );
+    }
+
+    #[test]
+    fn test_split_validation() {
+        let env = Env::default();
+
+        let valid = PayoutDestination {
+            splits: Vec::from_array(&env, [split(&env, 7_000), split(&env, 3_000)]),
+        };
+        assert!(valid.is_valid());
+
+        let short = PayoutDestination {
+            splits: Vec::from_array(&env, [split(&env, 7_000), split(&env, 2_000)]),
+        };
+        assert!(!short.is_valid());
+
+        let zero_share = PayoutDestination {
+            splits: Vec::from_array(&env, [split(&env, 10_000), split(&env, 0)]),
+        };
+        assert!(!zero_share.is_valid());
+
+        let empty = PayoutDestination {
+            splits: Vec::new(&env),
+        };
+        assert!(!empty.is_valid());
+
+        let duplicate = split(&env, 5_000);
+        let duplicated = PayoutDestination {
+            splits: Vec::from_array(&env, [duplicate.clone(), duplicate]),
+        };
+        assert!(!duplicated.is_valid());
+
+        let mut too_many = Vec::new(&env);
+        for _ in 0..(MAX_PAYOUT_SPLITS + 1) {
+            too_many.push_back(split(&env, 1_000));
+        }
+        assert!(!PayoutDestination { splits: too_many }.is_valid());
+    }
+
+    #[test]
+    fn test_allocate_assigns_remainder_to_first() {
+        let env = Env::default();
+        let destination = PayoutDestination {
+            splits: Vec::from_array(
+                &env,
+                [split(&env, 3_334), split(&env, 3_333), split(&env, 3_333)],
+            ),
+        };
+
+        let transfers = destination.allocate(&env, 100);
+        assert_eq!(transfers.get(0).unwrap().amount, 34);
+        assert_eq!(transfers.get(1).unwrap().amount, 33);
+        assert_eq!(transfers.get(2).unwrap().amount, 33);
+    }
+}
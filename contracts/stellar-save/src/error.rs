@@ -22,6 +22,14 @@ pub enum StellarSaveError {
     /// Error Code: 1003
     InvalidState = 1003,
 
+    /// The group's scheduled start time has not been reached yet.
+    /// Error Code: 1004
+    ActivationNotDue = 1004,
+
+    /// The group has no token configured for on-chain transfers.
+    /// Error Code: 1005
+    TokenNotConfigured = 1005,
+
     // Member-related errors (2000-2999)
     /// The address is already a member of this group.
     /// Error Code: 2001
@@ -35,6 +43,26 @@ pub enum StellarSaveError {
     /// Error Code: 2003
     Unauthorized = 2003,
 
+    /// The address is not allowlisted and did not present a valid invite.
+    /// Error Code: 2004
+    NotInvited = 2004,
+
+    /// The invite code does not match any outstanding invite for this group.
+    /// Error Code: 2005
+    InviteNotFound = 2005,
+
+    /// The invite code has expired.
+    /// Error Code: 2006
+    InviteExpired = 2006,
+
+    /// No pending join request exists for this address.
+    /// Error Code: 2007
+    JoinRequestNotFound = 2007,
+
+    /// The join request has expired and must be resubmitted.
+    /// Error Code: 2008
+    JoinRequestExpired = 2008,
+
     // Contribution-related errors (3000-3999)
     /// The contribution amount is invalid (zero, negative, or incorrect).
     /// Error Code: 3001
@@ -52,6 +80,18 @@ pub enum StellarSaveError {
     /// Error Code: 3004
     ContributionNotFound = 3004,
 
+    /// The number of prepaid cycles is zero or exceeds the cycles left to pay.
+    /// Error Code: 3005
+    InvalidPrepayment = 3005,
+
+    /// The member has no prepaid contributions in this group.
+    /// Error Code: 3006
+    PrepaymentNotFound = 3006,
+
+    /// The payer's token balance or allowance does not cover the payment.
+    /// Error Code: 3007
+    InsufficientFunds = 3007,
+
     // Payout-related errors (4000-4999)
     /// The payout operation failed due to insufficient funds or transfer error.
     /// Error Code: 4001
@@ -65,6 +105,39 @@ pub enum StellarSaveError {
     /// Error Code: 4003
     InvalidRecipient = 4003,
 
+    /// The payout destination splits are invalid or can no longer be changed.
+    /// Error Code: 4004
+    InvalidPayoutSplit = 4004,
+
+    /// The member has already withdrawn their savings from the club.
+    /// Error Code: 4005
+    AlreadyWithdrawn = 4005,
+
+    /// The vault is not on the contract's vault allowlist.
+    /// Error Code: 4006
+    VaultNotAllowed = 4006,
+
+    // Governance-related errors (5000-5999)
+    /// The specified proposal does not exist.
+    /// Error Code: 5001
+    ProposalNotFound = 5001,
+
+    /// The proposal is no longer accepting votes.
+    /// Error Code: 5002
+    VotingClosed = 5002,
+
+    /// The member has already voted on this proposal.
+    /// Error Code: 5003
+    AlreadyVoted = 5003,
+
+    /// The voting window has not ended yet, so the proposal cannot be finalized.
+    /// Error Code: 5004
+    VotingStillOpen = 5004,
+
+    /// The governance configuration or proposed action is invalid.
+    /// Error Code: 5005
+    InvalidProposal = 5005,
+
     // System-related errors (9000-9999)
     /// An internal contract error occurred.
     /// Error Code: 9001
@@ -96,6 +169,12 @@ impl StellarSaveError {
             StellarSaveError::InvalidState => {
                 "The group is not in a valid state for this operation. Check group status."
             }
+            StellarSaveError::ActivationNotDue => {
+                "The group's scheduled start time has not been reached yet."
+            }
+            StellarSaveError::TokenNotConfigured => {
+                "The group has no token configured. An admin must set one before the group starts."
+            }
 
             // Member-related errors
             StellarSaveError::AlreadyMember => {
@@ -107,6 +186,21 @@ impl StellarSaveError {
             StellarSaveError::Unauthorized => {
                 "You are not authorized to perform this operation. Check permissions."
             }
+            StellarSaveError::NotInvited => {
+                "This group is invite-only. Ask the creator for an invite or allowlist entry."
+            }
+            StellarSaveError::InviteNotFound => {
+                "The invite code is invalid, revoked, or has already been used."
+            }
+            StellarSaveError::InviteExpired => {
+                "The invite code has expired. Ask the creator for a new invite."
+            }
+            StellarSaveError::JoinRequestNotFound => {
+                "No pending join request was found for this address."
+            }
+            StellarSaveError::JoinRequestExpired => {
+                "The join request has expired. Submit a new request to join."
+            }
 
             // Contribution-related errors
             StellarSaveError::InvalidAmount => {
@@ -121,6 +215,15 @@ impl StellarSaveError {
             StellarSaveError::ContributionNotFound => {
                 "The contribution record was not found for the specified member and cycle."
             }
+            StellarSaveError::InvalidPrepayment => {
+                "Prepaid cycles must be at least 1 and cannot exceed the cycles left to pay."
+            }
+            StellarSaveError::PrepaymentNotFound => {
+                "No prepaid contributions were found for this member."
+            }
+            StellarSaveError::InsufficientFunds => {
+                "The payer's token balance or allowance for this contract does not cover the payment."
+            }
 
             // Payout-related errors
             StellarSaveError::PayoutFailed => {
@@ -132,6 +235,32 @@ impl StellarSaveError {
             StellarSaveError::InvalidRecipient => {
                 "The specified recipient is not eligible for payout in this cycle."
             }
+            StellarSaveError::InvalidPayoutSplit => {
+                "Payout splits must have 1-5 distinct destinations totalling 10000 basis points."
+            }
+            StellarSaveError::AlreadyWithdrawn => {
+                "The member has already withdrawn their savings from this club."
+            }
+            StellarSaveError::VaultNotAllowed => {
+                "The vault has not been allowlisted by the contract admin."
+            }
+
+            // Governance-related errors
+            StellarSaveError::ProposalNotFound => {
+                "The specified proposal does not exist. Please verify the proposal ID."
+            }
+            StellarSaveError::VotingClosed => {
+                "Voting on this proposal has closed or the proposal has already been resolved."
+            }
+            StellarSaveError::AlreadyVoted => {
+                "You have already voted on this proposal. Votes cannot be changed."
+            }
+            StellarSaveError::VotingStillOpen => {
+                "The voting window is still open. Wait until it ends to finalize the proposal."
+            }
+            StellarSaveError::InvalidProposal => {
+                "The proposal or governance settings are invalid for this group."
+            }
 
             // System-related errors
             StellarSaveError::InternalError => {
@@ -161,6 +290,7 @@ impl StellarSaveError {
             2000..=2999 => ErrorCategory::Member,
             3000..=3999 => ErrorCategory::Contribution,
             4000..=4999 => ErrorCategory::Payout,
+            5000..=5999 => ErrorCategory::Governance,
             9000..=9999 => ErrorCategory::System,
             _ => ErrorCategory::Unknown,
         }
@@ -183,6 +313,9 @@ pub enum ErrorCategory {
     /// Errors related to payout operations.
     Payout,
 
+    /// Errors related to proposals and voting.
+    Governance,
+
     /// System-level errors and internal failures.
     System,
 
@@ -206,18 +339,37 @@ mod tests {
         assert_eq!(StellarSaveError::GroupNotFound.code(), 1001);
         assert_eq!(StellarSaveError::GroupFull.code(), 1002);
         assert_eq!(StellarSaveError::InvalidState.code(), 1003);
+        assert_eq!(StellarSaveError::ActivationNotDue.code(), 1004);
+        assert_eq!(StellarSaveError::TokenNotConfigured.code(), 1005);
 
         assert_eq!(StellarSaveError::AlreadyMember.code(), 2001);
         assert_eq!(StellarSaveError::NotMember.code(), 2002);
         assert_eq!(StellarSaveError::Unauthorized.code(), 2003);
+        assert_eq!(StellarSaveError::NotInvited.code(), 2004);
+        assert_eq!(StellarSaveError::InviteNotFound.code(), 2005);
+        assert_eq!(StellarSaveError::InviteExpired.code(), 2006);
+        assert_eq!(StellarSaveError::JoinRequestNotFound.code(), 2007);
+        assert_eq!(StellarSaveError::JoinRequestExpired.code(), 2008);
 
         assert_eq!(StellarSaveError::InvalidAmount.code(), 3001);
         assert_eq!(StellarSaveError::AlreadyContributed.code(), 3002);
         assert_eq!(StellarSaveError::CycleNotComplete.code(), 3003);
+        assert_eq!(StellarSaveError::InvalidPrepayment.code(), 3005);
+        assert_eq!(StellarSaveError::PrepaymentNotFound.code(), 3006);
+        assert_eq!(StellarSaveError::InsufficientFunds.code(), 3007);
 
         assert_eq!(StellarSaveError::PayoutFailed.code(), 4001);
         assert_eq!(StellarSaveError::PayoutAlreadyProcessed.code(), 4002);
         assert_eq!(StellarSaveError::InvalidRecipient.code(), 4003);
+        assert_eq!(StellarSaveError::InvalidPayoutSplit.code(), 4004);
+        assert_eq!(StellarSaveError::AlreadyWithdrawn.code(), 4005);
+        assert_eq!(StellarSaveError::VaultNotAllowed.code(), 4006);
+
+        assert_eq!(StellarSaveError::ProposalNotFound.code(), 5001);
+        assert_eq!(StellarSaveError::VotingClosed.code(), 5002);
+        assert_eq!(StellarSaveError::AlreadyVoted.code(), 5003);
+        assert_eq!(StellarSaveError::VotingStillOpen.code(), 5004);
+        assert_eq!(StellarSaveError::InvalidProposal.code(), 5005);
 
         assert_eq!(StellarSaveError::InternalError.code(), 9001);
         assert_eq!(StellarSaveError::DataCorruption.code(), 9002);
@@ -259,6 +411,15 @@ mod tests {
             ErrorCategory::Payout
         );
 
+        assert_eq!(
+            StellarSaveError::ProposalNotFound.category(),
+            ErrorCategory::Governance
+        );
+        assert_eq!(
+            StellarSaveError::AlreadyVoted.category(),
+            ErrorCategory::Governance
+        );
+
         assert_eq!(
             StellarSaveError::InternalError.category(),
             ErrorCategory::System
@@ -276,15 +437,33 @@ mod tests {
             StellarSaveError::GroupNotFound,
             StellarSaveError::GroupFull,
             StellarSaveError::InvalidState,
+            StellarSaveError::ActivationNotDue,
+            StellarSaveError::TokenNotConfigured,
             StellarSaveError::AlreadyMember,
             StellarSaveError::NotMember,
             StellarSaveError::Unauthorized,
+            StellarSaveError::NotInvited,
+            StellarSaveError::InviteNotFound,
+            StellarSaveError::InviteExpired,
+            StellarSaveError::JoinRequestNotFound,
+            StellarSaveError::JoinRequestExpired,
             StellarSaveError::InvalidAmount,
             StellarSaveError::AlreadyContributed,
             StellarSaveError::CycleNotComplete,
+            StellarSaveError::InvalidPrepayment,
+            StellarSaveError::PrepaymentNotFound,
+            StellarSaveError::InsufficientFunds,
             StellarSaveError::PayoutFailed,
             StellarSaveError::PayoutAlreadyProcessed,
             StellarSaveError::InvalidRecipient,
+            StellarSaveError::InvalidPayoutSplit,
+            StellarSaveError::AlreadyWithdrawn,
+            StellarSaveError::VaultNotAllowed,
+            StellarSaveError::ProposalNotFound,
+            StellarSaveError::VotingClosed,
+            StellarSaveError::AlreadyVoted,
+            StellarSaveError::VotingStillOpen,
+            StellarSaveError::InvalidProposal,
             StellarSaveError::InternalError,
             StellarSaveError::DataCorruption,
         ];
@@ -1,4 +1,24 @@
-use soroban_sdk::{contracttype, Address, Env};
+use crate::activation::ActivationPolicy;
+use crate::club::SavingsClubConfig;
+use crate::destination::PayoutDestination;
+use crate::governance::{GovernanceConfig, ProposalAction, ProposalStatus};
+use crate::invite::JoinPolicy;
+use crate::role::GroupRole;
+use crate::round::RenewalPolicy;
+use crate::schedule::CycleSchedule;
+use crate::vault::GroupVault;
+use soroban_sdk::{contracttype, Address, BytesN, Env, IntoVal, Symbol, Val, Vec};
+
+/// First topic of every event the contract publishes.
+pub const EVENT_CONTRACT_NAME: &str = "stellar_save";
+
+/// Version of the event schema, the third topic of every event. Bumped
+/// whenever the fields of an existing event change.
+pub const EVENT_SCHEMA_VERSION: u32 = 1;
+
+/// Group ID topic of events that concern the whole contract rather than
+/// one group. Group IDs start at 1, so it never names a real group.
+pub const CONTRACT_EVENT_GROUP_ID: u64 = 0;
 
 /// Event emitted when a new savings group is created.
 #[contracttype]
@@ -32,18 +52,128 @@ pub struct MemberLeft {
     pub left_at: u64,
 }
 
+/// Event emitted when a group admin issues a one-time invite. Only the hash
+/// of the code is published.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InviteCreated {
+    pub group_id: u64,
+    pub code_hash: BytesN<32>,
+    pub issued_by: Address,
+    pub expires_at: u64,
+    pub created_at: u64,
+}
+
+/// Event emitted when a one-time invite code is used to join a group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InviteUsed {
+    pub group_id: u64,
+    pub member: Address,
+    pub code_hash: BytesN<32>,
+    pub used_at: u64,
+}
+
+/// Event emitted when a group creator revokes an outstanding invite.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InviteRevoked {
+    pub group_id: u64,
+    pub code_hash: BytesN<32>,
+    pub revoked_at: u64,
+}
+
+/// Event emitted when an address is added to or removed from a group's
+/// allowlist.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllowlistUpdated {
+    pub group_id: u64,
+    pub address: Address,
+    pub allowed: bool,
+    pub updated_by: Address,
+    pub updated_at: u64,
+}
+
+/// Event emitted when an address asks to join a group that requires approval.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JoinRequested {
+    pub group_id: u64,
+    pub member: Address,
+    pub message_hash: BytesN<32>,
+    pub requested_at: u64,
+}
+
+/// Event emitted when a join request is approved or rejected.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JoinRequestDecided {
+    pub group_id: u64,
+    pub member: Address,
+    pub approved: bool,
+    pub decided_by: Address,
+    pub decided_at: u64,
+}
+
+/// Event emitted when a role is granted to or revoked from an address.
+/// A revocation is reported with `role` set to `GroupRole::Member`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoleChanged {
+    pub group_id: u64,
+    pub address: Address,
+    pub role: GroupRole,
+    pub changed_by: Address,
+    pub changed_at: u64,
+}
+
+/// Event emitted when group ownership moves to a new address.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OwnershipTransferred {
+    pub group_id: u64,
+    pub old_owner: Address,
+    pub new_owner: Address,
+    pub transferred_at: u64,
+}
+
 /// Event emitted when a member makes a contribution.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct ContributionMade {
     pub group_id: u64,
     pub contributor: Address,
+    pub payer: Address,
     pub amount: i128,
     pub cycle: u32,
     pub cycle_total: i128,
     pub contributed_at: u64,
 }
 
+/// Event emitted when a member pays part of a cycle's contribution.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InstallmentPaid {
+    pub group_id: u64,
+    pub member: Address,
+    pub cycle: u32,
+    pub amount: i128,
+    pub outstanding: i128,
+    pub paid_at: u64,
+}
+
+/// Event emitted when an automatic contribution pull fails and the
+/// member's contribution is marked late.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoDebitFailed {
+    pub group_id: u64,
+    pub member: Address,
+    pub cycle: u32,
+    pub failed_at: u64,
+}
+
 /// Event emitted when a payout is executed.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -66,6 +196,18 @@ pub struct GroupCompleted {
     pub completed_at: u64,
 }
 
+/// Event emitted when a group is cancelled, either because its scheduled
+/// start passed without reaching the minimum member count or by a
+/// governance vote.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupCancelled {
+    pub group_id: u64,
+    pub member_count: u32,
+    pub min_members: u32,
+    pub cancelled_at: u64,
+}
+
 /// Event emitted when a group's status changes.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -77,134 +219,1432 @@ pub struct GroupStatusChanged {
     pub changed_at: u64,
 }
 
-/// Utility functions for emitting events.
-pub struct EventEmitter;
+/// Event emitted when a member is removed from a group, with the settlement
+/// of their net position (positive = owed to the member).
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberRemoved {
+    pub group_id: u64,
+    pub member: Address,
+    pub removed_by: Address,
+    pub net_position: i128,
+    pub member_count: u32,
+    pub removed_at: u64,
+}
 
-impl EventEmitter {
-    pub fn emit_group_created(
-        env: &Env,
-        group_id: u64,
-        creator: Address,
-        contribution_amount: i128,
-        cycle_duration: u64,
-        max_members: u32,
-        created_at: u64,
-    ) {
-        let event = GroupCreated {
-            group_id,
-            creator,
-            contribution_amount,
-            cycle_duration,
-            max_members,
-            created_at,
-        };
-        env.events().publish(("group_created",), event);
-    }
+/// Event emitted when a member's seat is handed to a new address.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberReplaced {
+    pub group_id: u64,
+    pub old_member: Address,
+    pub new_member: Address,
+    pub payout_position: u32,
+    pub replaced_by: Address,
+    pub replaced_at: u64,
+}
 
-    pub fn emit_member_joined(
-        env: &Env,
-        group_id: u64,
-        member: Address,
-        member_count: u32,
-        joined_at: u64,
-    ) {
-        let event = MemberJoined {
-            group_id,
-            member,
-            member_count,
-            joined_at,
-        };
-        env.events().publish(("member_joined",), event);
-    }
+/// Event emitted when a membership moves to a new address.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberAddressRotated {
+    pub group_id: u64,
+    pub old_address: Address,
+    pub new_address: Address,
+    pub rotated_by: Address,
+    pub rotated_at: u64,
+}
 
-    pub fn emit_member_left(
-        env: &Env,
-        group_id: u64,
-        member: Address,
-        member_count: u32,
-        left_at: u64,
-    ) {
-        let event = MemberLeft {
-            group_id,
-            member,
-            member_count,
-            left_at,
-        };
-        env.events().publish(("member_left",), event);
-    }
+/// Event emitted when a group moves on to its next cycle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CycleAdvanced {
+    pub group_id: u64,
+    pub cycle: u32,
+    pub advanced_by: Address,
+    pub advanced_at: u64,
+}
 
-    pub fn emit_contribution_made(
-        env: &Env,
-        group_id: u64,
-        contributor: Address,
-        amount: i128,
-        cycle: u32,
-        cycle_total: i128,
-        contributed_at: u64,
-    ) {
-        let event = ContributionMade {
-            group_id,
-            contributor,
-            amount,
-            cycle,
-            cycle_total,
-            contributed_at,
-        };
-        env.events().publish(("contribution_made",), event);
-    }
+/// Event emitted when the last cycle of a renewing group's round is paid
+/// and members can choose whether to take part in the next round.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RenewalWindowOpened {
+    pub group_id: u64,
+    pub round: u32,
+    pub closes_at: u64,
+}
 
-    pub fn emit_payout_executed(
-        env: &Env,
-        group_id: u64,
-        recipient: Address,
-        amount: i128,
-        cycle: u32,
-        executed_at: u64,
-    ) {
-        let event = PayoutExecuted {
-            group_id,
-            recipient,
-            amount,
-            cycle,
-            executed_at,
-        };
-        env.events().publish(("payout_executed",), event);
-    }
+/// Event emitted when a renewing group starts a new round.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundStarted {
+    pub group_id: u64,
+    pub round: u32,
+    pub member_count: u32,
+    pub started_at: u64,
+}
 
-    pub fn emit_group_completed(
-        env: &Env,
-        group_id: u64,
-        creator: Address,
-        total_cycles: u32,
-        total_distributed: i128,
-        completed_at: u64,
-    ) {
-        let event = GroupCompleted {
-            group_id,
-            creator,
-            total_cycles,
-            total_distributed,
-            completed_at,
-        };
-        env.events().publish(("group_completed",), event);
-    }
+/// Event emitted when a savings club member withdraws their savings.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SavingsWithdrawn {
+    pub group_id: u64,
+    pub member: Address,
+    pub amount: i128,
+    pub penalty: i128,
+    pub withdrawn_at: u64,
+}
 
-    pub fn emit_group_status_changed(
-        env: &Env,
-        group_id: u64,
-        old_status: u32,
-        new_status: u32,
-        changed_by: Address,
-        changed_at: u64,
-    ) {
-        let event = GroupStatusChanged {
-            group_id,
-            old_status,
-            new_status,
-            changed_by,
-            changed_at,
+/// Event emitted when collected fees are withdrawn, either protocol fees
+/// (`group_id` is `None`) or a group's organizer fees.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeesWithdrawn {
+    pub group_id: Option<u64>,
+    pub recipient: Address,
+    pub amount: i128,
+    pub withdrawn_at: u64,
+}
+
+/// Event emitted when a group's insurance reserve pays a missed
+/// contribution.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsuranceDrawn {
+    pub group_id: u64,
+    pub member: Address,
+    pub cycle: u32,
+    pub amount: i128,
+    pub reserve_remaining: i128,
+}
+
+/// Event emitted when a completed group's unused insurance reserve is
+/// returned to its members.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsuranceDistributed {
+    pub group_id: u64,
+    pub amount: i128,
+    pub member_count: u32,
+    pub distributed_at: u64,
+}
+
+/// Event emitted when a group's parked escrow comes back from its vault
+/// for a payout.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultHarvested {
+    pub group_id: u64,
+    pub cycle: u32,
+    pub principal: i128,
+    pub yield_amount: i128,
+    pub harvested_at: u64,
+}
+
+/// Event emitted when a member claims the vault yield credited to them.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultYieldClaimed {
+    pub group_id: u64,
+    pub member: Address,
+    pub amount: i128,
+    pub claimed_at: u64,
+}
+
+/// Event emitted when a Pending group's terms are changed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupUpdated {
+    pub group_id: u64,
+    pub updated_by: Address,
+    pub contribution_amount: i128,
+    pub cycle_duration: u64,
+    pub max_members: u32,
+    pub updated_at: u64,
+}
+
+/// Event emitted when an empty group is deleted.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupDeleted {
+    pub group_id: u64,
+    pub deleted_by: Address,
+    pub deleted_at: u64,
+}
+
+/// Event emitted when a group is activated and its first cycle starts.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupActivated {
+    pub group_id: u64,
+    pub member_count: u32,
+    pub activated_at: u64,
+}
+
+/// Event emitted when an admin changes how many shares a member holds.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberSharesUpdated {
+    pub group_id: u64,
+    pub member: Address,
+    pub share_units: u32,
+    pub updated_at: u64,
+}
+
+/// Event emitted when a group's renewal policy is set.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RenewalPolicyUpdated {
+    pub group_id: u64,
+    pub updated_by: Address,
+    pub policy: RenewalPolicy,
+    pub updated_at: u64,
+}
+
+/// Event emitted when a member opts in or out of the next round.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RenewalChoiceUpdated {
+    pub group_id: u64,
+    pub member: Address,
+    pub renew: bool,
+    pub updated_at: u64,
+}
+
+/// Event emitted when a group is turned into a savings club.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SavingsClubUpdated {
+    pub group_id: u64,
+    pub updated_by: Address,
+    pub config: SavingsClubConfig,
+    pub updated_at: u64,
+}
+
+/// Event emitted when a club member sets their savings goal.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SavingsGoalUpdated {
+    pub group_id: u64,
+    pub member: Address,
+    pub goal: i128,
+    pub updated_at: u64,
+}
+
+/// Event emitted when a group's organizer fee is set.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OrganizerFeeUpdated {
+    pub group_id: u64,
+    pub updated_by: Address,
+    pub fee_bps: u32,
+    pub updated_at: u64,
+}
+
+/// Event emitted when a group's insurance levy is set.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InsuranceLevyUpdated {
+    pub group_id: u64,
+    pub updated_by: Address,
+    pub levy_bps: u32,
+    pub updated_at: u64,
+}
+
+/// Event emitted when the admin adds a vault to or removes it from the
+/// allowlist. Published under `CONTRACT_EVENT_GROUP_ID`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VaultAllowlistUpdated {
+    pub vault: Address,
+    pub allowed: bool,
+    pub updated_at: u64,
+}
+
+/// Event emitted when a group opts into a vault.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupVaultUpdated {
+    pub group_id: u64,
+    pub updated_by: Address,
+    pub setting: GroupVault,
+    pub updated_at: u64,
+}
+
+/// Event emitted when a group opts back out of its vault.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupVaultCleared {
+    pub group_id: u64,
+    pub cleared_by: Address,
+    pub cleared_at: u64,
+}
+
+/// Event emitted when a group's contribution token is set.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupTokenUpdated {
+    pub group_id: u64,
+    pub updated_by: Address,
+    pub token: Address,
+    pub updated_at: u64,
+}
+
+/// Event emitted when a member enrolls in or cancels auto-debit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AutoDebitUpdated {
+    pub group_id: u64,
+    pub member: Address,
+    pub enrolled: bool,
+    pub updated_at: u64,
+}
+
+/// Event emitted when a member sets their payout destination.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutDestinationUpdated {
+    pub group_id: u64,
+    pub member: Address,
+    pub destination: PayoutDestination,
+    pub updated_at: u64,
+}
+
+/// Event emitted when a member clears their payout destination.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutDestinationCleared {
+    pub group_id: u64,
+    pub member: Address,
+    pub cleared_at: u64,
+}
+
+/// Event emitted when a group's join policy is set.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JoinPolicyUpdated {
+    pub group_id: u64,
+    pub updated_by: Address,
+    pub policy: JoinPolicy,
+    pub updated_at: u64,
+}
+
+/// Event emitted when a group's activation policy is set.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActivationPolicyUpdated {
+    pub group_id: u64,
+    pub updated_by: Address,
+    pub policy: ActivationPolicy,
+    pub updated_at: u64,
+}
+
+/// Event emitted when a group's cycle schedule is set.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CycleScheduleUpdated {
+    pub group_id: u64,
+    pub updated_by: Address,
+    pub schedule: CycleSchedule,
+    pub updated_at: u64,
+}
+
+/// Event emitted when a group's governance settings are set.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceConfigUpdated {
+    pub group_id: u64,
+    pub updated_by: Address,
+    pub config: GovernanceConfig,
+    pub updated_at: u64,
+}
+
+/// Event emitted when a member withdraws their contributions from a stalled
+/// group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyWithdrawal {
+    pub group_id: u64,
+    pub member: Address,
+    pub amount: i128,
+    pub withdrawn_at: u64,
+}
+
+/// Event emitted when a passed proposal extends the current cycle's
+/// deadline.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeadlineExtended {
+    pub group_id: u64,
+    pub cycle: u32,
+    pub deadline: u64,
+    pub extended_at: u64,
+}
+
+/// Event emitted when a passed proposal changes the payout order.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutOrderChanged {
+    pub group_id: u64,
+    pub order: Vec<Address>,
+    pub changed_at: u64,
+}
+
+/// Event emitted when a member pays contributions for future cycles.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrepaymentMade {
+    pub group_id: u64,
+    pub member: Address,
+    pub cycles: u32,
+    pub amount: i128,
+    pub cycles_remaining: u32,
+    pub prepaid_at: u64,
+}
+
+/// Event emitted when unused prepaid contributions are returned to a member.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PrepaymentRefunded {
+    pub group_id: u64,
+    pub member: Address,
+    pub cycles: u32,
+    pub amount: i128,
+    pub refunded_at: u64,
+}
+
+/// Event emitted when a member opens a governance proposal.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalCreated {
+    pub group_id: u64,
+    pub proposal_id: u32,
+    pub proposer: Address,
+    pub action: ProposalAction,
+    pub voting_ends_at: u64,
+}
+
+/// Event emitted when a member votes on a proposal.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoteCast {
+    pub group_id: u64,
+    pub proposal_id: u32,
+    pub voter: Address,
+    pub support: bool,
+    pub voted_at: u64,
+}
+
+/// Event emitted when a proposal is executed, rejected, or fails to execute.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProposalResolved {
+    pub group_id: u64,
+    pub proposal_id: u32,
+    pub status: ProposalStatus,
+    pub yes_votes: u32,
+    pub no_votes: u32,
+    pub resolved_at: u64,
+}
+
+/// Utility functions for emitting events.
+pub struct EventEmitter;
+
+impl EventEmitter {
+    /// Publishes `event` under the contract's topic layout:
+    /// `(EVENT_CONTRACT_NAME, name, EVENT_SCHEMA_VERSION, group_id)`.
+    ///
+    /// `#[contractevent]` derives its own topics from the event type, which
+    /// would break this versioned layout that indexers decode, so the
+    /// deprecated `publish` stays for now.
+    #[allow(deprecated)]
+    fn publish<T: IntoVal<Env, Val>>(env: &Env, name: &str, group_id: u64, event: T) {
+        env.events().publish(
+            (
+                Symbol::new(env, EVENT_CONTRACT_NAME),
+                Symbol::new(env, name),
+                EVENT_SCHEMA_VERSION,
+                group_id,
+            ),
+            event,
+        );
+    }
+
+    pub fn emit_group_created(
+        env: &Env,
+        group_id: u64,
+        creator: Address,
+        contribution_amount: i128,
+        cycle_duration: u64,
+        max_members: u32,
+        created_at: u64,
+    ) {
+        let event = GroupCreated {
+            group_id,
+            creator,
+            contribution_amount,
+            cycle_duration,
+            max_members,
+            created_at,
+        };
+        Self::publish(env, "group_created", group_id, event);
+    }
+
+    pub fn emit_member_joined(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        member_count: u32,
+        joined_at: u64,
+    ) {
+        let event = MemberJoined {
+            group_id,
+            member,
+            member_count,
+            joined_at,
+        };
+        Self::publish(env, "member_joined", group_id, event);
+    }
+
+    pub fn emit_member_left(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        member_count: u32,
+        left_at: u64,
+    ) {
+        let event = MemberLeft {
+            group_id,
+            member,
+            member_count,
+            left_at,
+        };
+        Self::publish(env, "member_left", group_id, event);
+    }
+
+    pub fn emit_invite_created(
+        env: &Env,
+        group_id: u64,
+        code_hash: BytesN<32>,
+        issued_by: Address,
+        expires_at: u64,
+        created_at: u64,
+    ) {
+        let event = InviteCreated {
+            group_id,
+            code_hash,
+            issued_by,
+            expires_at,
+            created_at,
+        };
+        Self::publish(env, "invite_created", group_id, event);
+    }
+
+    pub fn emit_invite_used(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        code_hash: BytesN<32>,
+        used_at: u64,
+    ) {
+        let event = InviteUsed {
+            group_id,
+            member,
+            code_hash,
+            used_at,
+        };
+        Self::publish(env, "invite_used", group_id, event);
+    }
+
+    pub fn emit_invite_revoked(env: &Env, group_id: u64, code_hash: BytesN<32>, revoked_at: u64) {
+        let event = InviteRevoked {
+            group_id,
+            code_hash,
+            revoked_at,
+        };
+        Self::publish(env, "invite_revoked", group_id, event);
+    }
+
+    pub fn emit_allowlist_updated(
+        env: &Env,
+        group_id: u64,
+        address: Address,
+        allowed: bool,
+        updated_by: Address,
+        updated_at: u64,
+    ) {
+        let event = AllowlistUpdated {
+            group_id,
+            address,
+            allowed,
+            updated_by,
+            updated_at,
+        };
+        Self::publish(env, "allowlist_updated", group_id, event);
+    }
+
+    pub fn emit_join_requested(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        message_hash: BytesN<32>,
+        requested_at: u64,
+    ) {
+        let event = JoinRequested {
+            group_id,
+            member,
+            message_hash,
+            requested_at,
+        };
+        Self::publish(env, "join_requested", group_id, event);
+    }
+
+    pub fn emit_join_request_decided(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        approved: bool,
+        decided_by: Address,
+        decided_at: u64,
+    ) {
+        let event = JoinRequestDecided {
+            group_id,
+            member,
+            approved,
+            decided_by,
+            decided_at,
+        };
+        Self::publish(env, "join_request_decided", group_id, event);
+    }
+
+    pub fn emit_role_changed(
+        env: &Env,
+        group_id: u64,
+        address: Address,
+        role: GroupRole,
+        changed_by: Address,
+        changed_at: u64,
+    ) {
+        let event = RoleChanged {
+            group_id,
+            address,
+            role,
+            changed_by,
+            changed_at,
+        };
+        Self::publish(env, "role_changed", group_id, event);
+    }
+
+    pub fn emit_ownership_transferred(
+        env: &Env,
+        group_id: u64,
+        old_owner: Address,
+        new_owner: Address,
+        transferred_at: u64,
+    ) {
+        let event = OwnershipTransferred {
+            group_id,
+            old_owner,
+            new_owner,
+            transferred_at,
+        };
+        Self::publish(env, "ownership_transferred", group_id, event);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn emit_contribution_made(
+        env: &Env,
+        group_id: u64,
+        contributor: Address,
+        payer: Address,
+        amount: i128,
+        cycle: u32,
+        cycle_total: i128,
+        contributed_at: u64,
+    ) {
+        let event = ContributionMade {
+            group_id,
+            contributor,
+            payer,
+            amount,
+            cycle,
+            cycle_total,
+            contributed_at,
+        };
+        Self::publish(env, "contribution_made", group_id, event);
+    }
+
+    pub fn emit_installment_paid(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        cycle: u32,
+        amount: i128,
+        outstanding: i128,
+        paid_at: u64,
+    ) {
+        let event = InstallmentPaid {
+            group_id,
+            member,
+            cycle,
+            amount,
+            outstanding,
+            paid_at,
+        };
+        Self::publish(env, "installment_paid", group_id, event);
+    }
+
+    pub fn emit_auto_debit_failed(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        cycle: u32,
+        failed_at: u64,
+    ) {
+        let event = AutoDebitFailed {
+            group_id,
+            member,
+            cycle,
+            failed_at,
+        };
+        Self::publish(env, "auto_debit_failed", group_id, event);
+    }
+
+    pub fn emit_payout_executed(
+        env: &Env,
+        group_id: u64,
+        recipient: Address,
+        amount: i128,
+        cycle: u32,
+        executed_at: u64,
+    ) {
+        let event = PayoutExecuted {
+            group_id,
+            recipient,
+            amount,
+            cycle,
+            executed_at,
+        };
+        Self::publish(env, "payout_executed", group_id, event);
+    }
+
+    pub fn emit_group_completed(
+        env: &Env,
+        group_id: u64,
+        creator: Address,
+        total_cycles: u32,
+        total_distributed: i128,
+        completed_at: u64,
+    ) {
+        let event = GroupCompleted {
+            group_id,
+            creator,
+            total_cycles,
+            total_distributed,
+            completed_at,
+        };
+        Self::publish(env, "group_completed", group_id, event);
+    }
+
+    pub fn emit_group_cancelled(
+        env: &Env,
+        group_id: u64,
+        member_count: u32,
+        min_members: u32,
+        cancelled_at: u64,
+    ) {
+        let event = GroupCancelled {
+            group_id,
+            member_count,
+            min_members,
+            cancelled_at,
+        };
+        Self::publish(env, "group_cancelled", group_id, event);
+    }
+
+    pub fn emit_group_status_changed(
+        env: &Env,
+        group_id: u64,
+        old_status: u32,
+        new_status: u32,
+        changed_by: Address,
+        changed_at: u64,
+    ) {
+        let event = GroupStatusChanged {
+            group_id,
+            old_status,
+            new_status,
+            changed_by,
+            changed_at,
+        };
+        Self::publish(env, "group_status_changed", group_id, event);
+    }
+
+    pub fn emit_member_removed(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        removed_by: Address,
+        net_position: i128,
+        member_count: u32,
+        removed_at: u64,
+    ) {
+        let event = MemberRemoved {
+            group_id,
+            member,
+            removed_by,
+            net_position,
+            member_count,
+            removed_at,
+        };
+        Self::publish(env, "member_removed", group_id, event);
+    }
+
+    pub fn emit_member_replaced(
+        env: &Env,
+        group_id: u64,
+        old_member: Address,
+        new_member: Address,
+        payout_position: u32,
+        replaced_by: Address,
+        replaced_at: u64,
+    ) {
+        let event = MemberReplaced {
+            group_id,
+            old_member,
+            new_member,
+            payout_position,
+            replaced_by,
+            replaced_at,
+        };
+        Self::publish(env, "member_replaced", group_id, event);
+    }
+
+    pub fn emit_member_address_rotated(
+        env: &Env,
+        group_id: u64,
+        old_address: Address,
+        new_address: Address,
+        rotated_by: Address,
+        rotated_at: u64,
+    ) {
+        let event = MemberAddressRotated {
+            group_id,
+            old_address,
+            new_address,
+            rotated_by,
+            rotated_at,
+        };
+        Self::publish(env, "member_address_rotated", group_id, event);
+    }
+
+    pub fn emit_cycle_advanced(
+        env: &Env,
+        group_id: u64,
+        cycle: u32,
+        advanced_by: Address,
+        advanced_at: u64,
+    ) {
+        let event = CycleAdvanced {
+            group_id,
+            cycle,
+            advanced_by,
+            advanced_at,
+        };
+        Self::publish(env, "cycle_advanced", group_id, event);
+    }
+
+    pub fn emit_renewal_window_opened(env: &Env, group_id: u64, round: u32, closes_at: u64) {
+        let event = RenewalWindowOpened {
+            group_id,
+            round,
+            closes_at,
+        };
+        Self::publish(env, "renewal_window_opened", group_id, event);
+    }
+
+    pub fn emit_round_started(
+        env: &Env,
+        group_id: u64,
+        round: u32,
+        member_count: u32,
+        started_at: u64,
+    ) {
+        let event = RoundStarted {
+            group_id,
+            round,
+            member_count,
+            started_at,
+        };
+        Self::publish(env, "round_started", group_id, event);
+    }
+
+    pub fn emit_savings_withdrawn(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        amount: i128,
+        penalty: i128,
+        withdrawn_at: u64,
+    ) {
+        let event = SavingsWithdrawn {
+            group_id,
+            member,
+            amount,
+            penalty,
+            withdrawn_at,
+        };
+        Self::publish(env, "savings_withdrawn", group_id, event);
+    }
+
+    pub fn emit_fees_withdrawn(
+        env: &Env,
+        group_id: Option<u64>,
+        recipient: Address,
+        amount: i128,
+        withdrawn_at: u64,
+    ) {
+        let event = FeesWithdrawn {
+            group_id,
+            recipient,
+            amount,
+            withdrawn_at,
+        };
+        Self::publish(
+            env,
+            "fees_withdrawn",
+            group_id.unwrap_or(CONTRACT_EVENT_GROUP_ID),
+            event,
+        );
+    }
+
+    pub fn emit_insurance_drawn(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        cycle: u32,
+        amount: i128,
+        reserve_remaining: i128,
+    ) {
+        let event = InsuranceDrawn {
+            group_id,
+            member,
+            cycle,
+            amount,
+            reserve_remaining,
+        };
+        Self::publish(env, "insurance_drawn", group_id, event);
+    }
+
+    pub fn emit_insurance_distributed(
+        env: &Env,
+        group_id: u64,
+        amount: i128,
+        member_count: u32,
+        distributed_at: u64,
+    ) {
+        let event = InsuranceDistributed {
+            group_id,
+            amount,
+            member_count,
+            distributed_at,
+        };
+        Self::publish(env, "insurance_distributed", group_id, event);
+    }
+
+    pub fn emit_vault_harvested(
+        env: &Env,
+        group_id: u64,
+        cycle: u32,
+        principal: i128,
+        yield_amount: i128,
+        harvested_at: u64,
+    ) {
+        let event = VaultHarvested {
+            group_id,
+            cycle,
+            principal,
+            yield_amount,
+            harvested_at,
+        };
+        Self::publish(env, "vault_harvested", group_id, event);
+    }
+
+    pub fn emit_vault_yield_claimed(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        amount: i128,
+        claimed_at: u64,
+    ) {
+        let event = VaultYieldClaimed {
+            group_id,
+            member,
+            amount,
+            claimed_at,
+        };
+        Self::publish(env, "vault_yield_claimed", group_id, event);
+    }
+
+    pub fn emit_group_updated(
+        env: &Env,
+        group_id: u64,
+        updated_by: Address,
+        contribution_amount: i128,
+        cycle_duration: u64,
+        max_members: u32,
+        updated_at: u64,
+    ) {
+        let event = GroupUpdated {
+            group_id,
+            updated_by,
+            contribution_amount,
+            cycle_duration,
+            max_members,
+            updated_at,
+        };
+        Self::publish(env, "group_updated", group_id, event);
+    }
+
+    pub fn emit_group_deleted(env: &Env, group_id: u64, deleted_by: Address, deleted_at: u64) {
+        let event = GroupDeleted {
+            group_id,
+            deleted_by,
+            deleted_at,
+        };
+        Self::publish(env, "group_deleted", group_id, event);
+    }
+
+    pub fn emit_group_activated(env: &Env, group_id: u64, member_count: u32, activated_at: u64) {
+        let event = GroupActivated {
+            group_id,
+            member_count,
+            activated_at,
+        };
+        Self::publish(env, "group_activated", group_id, event);
+    }
+
+    pub fn emit_member_shares_updated(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        share_units: u32,
+        updated_at: u64,
+    ) {
+        let event = MemberSharesUpdated {
+            group_id,
+            member,
+            share_units,
+            updated_at,
+        };
+        Self::publish(env, "member_shares_updated", group_id, event);
+    }
+
+    pub fn emit_renewal_policy_updated(
+        env: &Env,
+        group_id: u64,
+        updated_by: Address,
+        policy: RenewalPolicy,
+        updated_at: u64,
+    ) {
+        let event = RenewalPolicyUpdated {
+            group_id,
+            updated_by,
+            policy,
+            updated_at,
+        };
+        Self::publish(env, "renewal_policy_updated", group_id, event);
+    }
+
+    pub fn emit_renewal_choice_updated(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        renew: bool,
+        updated_at: u64,
+    ) {
+        let event = RenewalChoiceUpdated {
+            group_id,
+            member,
+            renew,
+            updated_at,
+        };
+        Self::publish(env, "renewal_choice_updated", group_id, event);
+    }
+
+    pub fn emit_savings_club_updated(
+        env: &Env,
+        group_id: u64,
+        updated_by: Address,
+        config: SavingsClubConfig,
+        updated_at: u64,
+    ) {
+        let event = SavingsClubUpdated {
+            group_id,
+            updated_by,
+            config,
+            updated_at,
+        };
+        Self::publish(env, "savings_club_updated", group_id, event);
+    }
+
+    pub fn emit_savings_goal_updated(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        goal: i128,
+        updated_at: u64,
+    ) {
+        let event = SavingsGoalUpdated {
+            group_id,
+            member,
+            goal,
+            updated_at,
+        };
+        Self::publish(env, "savings_goal_updated", group_id, event);
+    }
+
+    pub fn emit_organizer_fee_updated(
+        env: &Env,
+        group_id: u64,
+        updated_by: Address,
+        fee_bps: u32,
+        updated_at: u64,
+    ) {
+        let event = OrganizerFeeUpdated {
+            group_id,
+            updated_by,
+            fee_bps,
+            updated_at,
+        };
+        Self::publish(env, "organizer_fee_updated", group_id, event);
+    }
+
+    pub fn emit_insurance_levy_updated(
+        env: &Env,
+        group_id: u64,
+        updated_by: Address,
+        levy_bps: u32,
+        updated_at: u64,
+    ) {
+        let event = InsuranceLevyUpdated {
+            group_id,
+            updated_by,
+            levy_bps,
+            updated_at,
+        };
+        Self::publish(env, "insurance_levy_updated", group_id, event);
+    }
+
+    pub fn emit_vault_allowlist_updated(env: &Env, vault: Address, allowed: bool, updated_at: u64) {
+        let event = VaultAllowlistUpdated {
+            vault,
+            allowed,
+            updated_at,
+        };
+        Self::publish(
+            env,
+            "vault_allowlist_updated",
+            CONTRACT_EVENT_GROUP_ID,
+            event,
+        );
+    }
+
+    pub fn emit_group_vault_updated(
+        env: &Env,
+        group_id: u64,
+        updated_by: Address,
+        setting: GroupVault,
+        updated_at: u64,
+    ) {
+        let event = GroupVaultUpdated {
+            group_id,
+            updated_by,
+            setting,
+            updated_at,
+        };
+        Self::publish(env, "group_vault_updated", group_id, event);
+    }
+
+    pub fn emit_group_vault_cleared(
+        env: &Env,
+        group_id: u64,
+        cleared_by: Address,
+        cleared_at: u64,
+    ) {
+        let event = GroupVaultCleared {
+            group_id,
+            cleared_by,
+            cleared_at,
+        };
+        Self::publish(env, "group_vault_cleared", group_id, event);
+    }
+
+    pub fn emit_group_token_updated(
+        env: &Env,
+        group_id: u64,
+        updated_by: Address,
+        token: Address,
+        updated_at: u64,
+    ) {
+        let event = GroupTokenUpdated {
+            group_id,
+            updated_by,
+            token,
+            updated_at,
+        };
+        Self::publish(env, "group_token_updated", group_id, event);
+    }
+
+    pub fn emit_auto_debit_updated(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        enrolled: bool,
+        updated_at: u64,
+    ) {
+        let event = AutoDebitUpdated {
+            group_id,
+            member,
+            enrolled,
+            updated_at,
+        };
+        Self::publish(env, "auto_debit_updated", group_id, event);
+    }
+
+    pub fn emit_payout_destination_updated(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        destination: PayoutDestination,
+        updated_at: u64,
+    ) {
+        let event = PayoutDestinationUpdated {
+            group_id,
+            member,
+            destination,
+            updated_at,
+        };
+        Self::publish(env, "payout_destination_updated", group_id, event);
+    }
+
+    pub fn emit_payout_destination_cleared(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        cleared_at: u64,
+    ) {
+        let event = PayoutDestinationCleared {
+            group_id,
+            member,
+            cleared_at,
+        };
+        Self::publish(env, "payout_destination_cleared", group_id, event);
+    }
+
+    pub fn emit_join_policy_updated(
+        env: &Env,
+        group_id: u64,
+        updated_by: Address,
+        policy: JoinPolicy,
+        updated_at: u64,
+    ) {
+        let event = JoinPolicyUpdated {
+            group_id,
+            updated_by,
+            policy,
+            updated_at,
+        };
+        Self::publish(env, "join_policy_updated", group_id, event);
+    }
+
+    pub fn emit_activation_policy_updated(
+        env: &Env,
+        group_id: u64,
+        updated_by: Address,
+        policy: ActivationPolicy,
+        updated_at: u64,
+    ) {
+        let event = ActivationPolicyUpdated {
+            group_id,
+            updated_by,
+            policy,
+            updated_at,
+        };
+        Self::publish(env, "activation_policy_updated", group_id, event);
+    }
+
+    pub fn emit_cycle_schedule_updated(
+        env: &Env,
+        group_id: u64,
+        updated_by: Address,
+        schedule: CycleSchedule,
+        updated_at: u64,
+    ) {
+        let event = CycleScheduleUpdated {
+            group_id,
+            updated_by,
+            schedule,
+            updated_at,
+        };
+        Self::publish(env, "cycle_schedule_updated", group_id, event);
+    }
+
+    pub fn emit_governance_config_updated(
+        env: &Env,
+        group_id: u64,
+        updated_by: Address,
+        config: GovernanceConfig,
+        updated_at: u64,
+    ) {
+        let event = GovernanceConfigUpdated {
+            group_id,
+            updated_by,
+            config,
+            updated_at,
+        };
+        Self::publish(env, "governance_config_updated", group_id, event);
+    }
+
+    pub fn emit_emergency_withdrawal(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        amount: i128,
+        withdrawn_at: u64,
+    ) {
+        let event = EmergencyWithdrawal {
+            group_id,
+            member,
+            amount,
+            withdrawn_at,
+        };
+        Self::publish(env, "emergency_withdrawal", group_id, event);
+    }
+
+    pub fn emit_deadline_extended(
+        env: &Env,
+        group_id: u64,
+        cycle: u32,
+        deadline: u64,
+        extended_at: u64,
+    ) {
+        let event = DeadlineExtended {
+            group_id,
+            cycle,
+            deadline,
+            extended_at,
+        };
+        Self::publish(env, "deadline_extended", group_id, event);
+    }
+
+    pub fn emit_payout_order_changed(
+        env: &Env,
+        group_id: u64,
+        order: Vec<Address>,
+        changed_at: u64,
+    ) {
+        let event = PayoutOrderChanged {
+            group_id,
+            order,
+            changed_at,
+        };
+        Self::publish(env, "payout_order_changed", group_id, event);
+    }
+
+    pub fn emit_prepayment_made(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        cycles: u32,
+        amount: i128,
+        cycles_remaining: u32,
+        prepaid_at: u64,
+    ) {
+        let event = PrepaymentMade {
+            group_id,
+            member,
+            cycles,
+            amount,
+            cycles_remaining,
+            prepaid_at,
+        };
+        Self::publish(env, "prepayment_made", group_id, event);
+    }
+
+    pub fn emit_prepayment_refunded(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        cycles: u32,
+        amount: i128,
+        refunded_at: u64,
+    ) {
+        let event = PrepaymentRefunded {
+            group_id,
+            member,
+            cycles,
+            amount,
+            refunded_at,
+        };
+        Self::publish(env, "prepayment_refunded", group_id, event);
+    }
+
+    pub fn emit_proposal_created(
+        env: &Env,
+        group_id: u64,
+        proposal_id: u32,
+        proposer: Address,
+        action: ProposalAction,
+        voting_ends_at: u64,
+    ) {
+        let event = ProposalCreated {
+            group_id,
+            proposal_id,
+            proposer,
+            action,
+            voting_ends_at,
+        };
+        Self::publish(env, "proposal_created", group_id, event);
+    }
+
+    pub fn emit_vote_cast(
+        env: &Env,
+        group_id: u64,
+        proposal_id: u32,
+        voter: Address,
+        support: bool,
+        voted_at: u64,
+    ) {
+        let event = VoteCast {
+            group_id,
+            proposal_id,
+            voter,
+            support,
+            voted_at,
+        };
+        Self::publish(env, "vote_cast", group_id, event);
+    }
+
+    pub fn emit_proposal_resolved(
+        env: &Env,
+        group_id: u64,
+        proposal_id: u32,
+        status: ProposalStatus,
+        yes_votes: u32,
+        no_votes: u32,
+        resolved_at: u64,
+    ) {
+        let event = ProposalResolved {
+            group_id,
+            proposal_id,
+            status,
+            yes_votes,
+            no_votes,
+            resolved_at,
         };
-        env.events().publish(("group_status_changed",), event);
+        Self::publish(env, "proposal_resolved", group_id, event);
     }
 }
 
@@ -0,0 +1,37 @@
+use crate::destination::PAYOUT_BPS_TOTAL;
+use crate::error::StellarSaveError;
+
+/// Highest protocol fee the contract config can set, in basis points (5%).
+pub const MAX_PROTOCOL_FEE_BPS: u32 = 500;
+
+/// Highest organizer fee a group can declare, in basis points (5%).
+pub const MAX_ORGANIZER_FEE_BPS: u32 = 500;
+
+/// Returns the fee of `fee_bps` basis points on `amount`, rounded down so
+/// rounding always favours the recipient.
+///
+/// # Errors
+/// `StellarSaveError::Overflow` if `amount` is too large to take a fee on.
+pub fn fee_amount(amount: i128, fee_bps: u32) -> Result<i128, StellarSaveError> {
+    amount
+        .checked_mul(fee_bps as i128)
+        .map(|scaled| scaled / PAYOUT_BPS_TOTAL as i128)
+        .ok_or(StellarSaveError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fee_amount() {
+        assert_eq!(fee_amount(10_000, 0), Ok(0));
+        assert_eq!(fee_amount(10_000, 150), Ok(150));
+        assert_eq!(fee_amount(999, 100), Ok(9));
+        assert_eq!(fee_amount(1_000, MAX_PROTOCOL_FEE_BPS), Ok(50));
+        assert_eq!(
+            fee_amount(i128::MAX, MAX_PROTOCOL_FEE_BPS),
+            Err(StellarSaveError::Overflow)
+        );
+    }
+}
@@ -0,0 +1,295 @@
+use soroban_sdk::{contracttype, Address, Vec};
+
+/// Basis points representing 100%.
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Default share of eligible members that must vote (50%).
+pub const DEFAULT_QUORUM_BPS: u32 = 5_000;
+
+/// Default share of cast votes that must be in favor (strict majority).
+pub const DEFAULT_THRESHOLD_BPS: u32 = 5_001;
+
+/// Default voting window (3 days).
+pub const DEFAULT_VOTING_PERIOD: u64 = 3 * 86400;
+
+/// Governance settings for a group.
+///
+/// A proposal passes when enough eligible members voted (`quorum_bps`) and
+/// enough of the cast votes are in favor (`threshold_bps`). Both are in basis
+/// points, so 5000 = 50%.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct GovernanceConfig {
+    /// Minimum share of eligible voters that must vote, in basis points.
+    pub quorum_bps: u32,
+
+    /// Minimum share of cast votes that must be in favor, in basis points.
+    pub threshold_bps: u32,
+
+    /// How long a proposal stays open for voting, in seconds.
+    pub voting_period: u64,
+}
+
+impl Default for GovernanceConfig {
+    fn default() -> Self {
+        Self {
+            quorum_bps: DEFAULT_QUORUM_BPS,
+            threshold_bps: DEFAULT_THRESHOLD_BPS,
+            voting_period: DEFAULT_VOTING_PERIOD,
+        }
+    }
+}
+
+impl GovernanceConfig {
+    /// Checks that quorum and threshold are between 0% (exclusive) and
+    /// 100% (inclusive) and that the voting period is non-zero.
+    pub fn is_valid(&self) -> bool {
+        (1..=BPS_DENOMINATOR).contains(&self.quorum_bps)
+            && (1..=BPS_DENOMINATOR).contains(&self.threshold_bps)
+            && self.voting_period > 0
+    }
+}
+
+/// Group actions that members can propose and vote on.
+///
+/// A passed proposal executes its action automatically.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ProposalAction {
+    /// Pause an Active group.
+    Pause,
+
+    /// Resume a Paused group.
+    Resume,
+
+    /// Cancel the group.
+    Cancel,
+
+    /// Remove a member (e.g. a defaulter) from the group.
+    RemoveMember(Address),
+
+    /// Hand a member's seat to a new address: `(old_member, new_member)`.
+    /// The new member consents by authorizing the proposal's creation.
+    ReplaceMember(Address, Address),
+
+    /// Move a member who lost their key to a new address:
+    /// `(old_address, new_address)`. The new address consents by authorizing
+    /// the proposal's creation.
+    RotateMemberAddress(Address, Address),
+
+    /// Extend the current cycle's deadline by the given number of seconds.
+    ExtendDeadline(u64),
+
+    /// Reorder the payout queue. Lists every member who has not received a
+    /// payout yet, in the order they should be paid.
+    ReorderPayouts(Vec<Address>),
+}
+
+/// Lifecycle of a proposal.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ProposalStatus {
+    /// Voting is in progress.
+    Open,
+
+    /// The proposal passed and its action was applied.
+    Executed,
+
+    /// The proposal did not reach quorum or threshold.
+    Rejected,
+
+    /// The proposal passed but its action could no longer be applied
+    /// (for example, the group was already cancelled).
+    Failed,
+}
+
+/// A governance proposal and its running tally.
+///
+/// Quorum and threshold are copied from the group's configuration when the
+/// proposal is created, so the rules a proposal was voted under stay on record.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Proposal {
+    /// Proposal ID, unique within the group.
+    pub id: u32,
+
+    /// Group the proposal belongs to.
+    pub group_id: u64,
+
+    /// Member who created the proposal.
+    pub proposer: Address,
+
+    /// Action executed if the proposal passes.
+    pub action: ProposalAction,
+
+    /// Timestamp the proposal was created.
+    pub created_at: u64,
+
+    /// Timestamp after which no more votes are accepted.
+    pub voting_ends_at: u64,
+
+    /// Number of members eligible to vote (members at creation time).
+    pub eligible_voters: u32,
+
+    /// Quorum in basis points.
+    pub quorum_bps: u32,
+
+    /// Threshold in basis points.
+    pub threshold_bps: u32,
+
+    /// Votes in favor.
+    pub yes_votes: u32,
+
+    /// Votes against.
+    pub no_votes: u32,
+
+    /// Current status.
+    pub status: ProposalStatus,
+
+    /// Timestamp the proposal was resolved (0 while open).
+    pub resolved_at: u64,
+}
+
+impl Proposal {
+    /// Total number of votes cast.
+    pub fn votes_cast(&self) -> u32 {
+        self.yes_votes + self.no_votes
+    }
+
+    /// Returns true if votes are still accepted at `current_time`.
+    pub fn is_voting_open(&self, current_time: u64) -> bool {
+        self.status == ProposalStatus::Open && current_time <= self.voting_ends_at
+    }
+
+    /// Returns true if the tally meets quorum and threshold, counting only the
+    /// votes cast so far.
+    pub fn has_passed(&self) -> bool {
+        let cast = self.votes_cast() as u64;
+        cast > 0
+            && cast * BPS_DENOMINATOR as u64 >= self.quorum_bps as u64 * self.eligible_voters as u64
+            && self.yes_votes as u64 * BPS_DENOMINATOR as u64 >= self.threshold_bps as u64 * cast
+    }
+
+    /// Returns the outcome if it can no longer change, regardless of how the
+    /// remaining members vote or whether they vote at all.
+    ///
+    /// # Returns
+    /// `Some(true)` if the proposal will pass, `Some(false)` if it will fail,
+    /// `None` if it still depends on outstanding votes.
+    pub fn decided_outcome(&self) -> Option<bool> {
+        let eligible = self.eligible_voters as u64;
+        let cast = self.votes_cast() as u64;
+        let yes = self.yes_votes as u64;
+        let denominator = BPS_DENOMINATOR as u64;
+
+        if cast >= eligible {
+            return Some(self.has_passed());
+        }
+
+        // Quorum is met and yes votes already exceed the threshold even if
+        // every remaining member votes against.
+        if cast * denominator >= self.quorum_bps as u64 * eligible
+            && yes * denominator >= self.threshold_bps as u64 * eligible
+        {
+            return Some(true);
+        }
+
+        // Even if every remaining member votes in favor, the threshold
+        // cannot be reached.
+        let remaining = eligible - cast;
+        if (yes + remaining) * denominator < self.threshold_bps as u64 * eligible {
+            return Some(false);
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    fn proposal(env: &Env, eligible_voters: u32, yes_votes: u32, no_votes: u32) -> Proposal {
+        Proposal {
+            id: 0,
+            group_id: 1,
+            proposer: Address::generate(env),
+            action: ProposalAction::Pause,
+            created_at: 1000,
+            voting_ends_at: 2000,
+            eligible_voters,
+            quorum_bps: DEFAULT_QUORUM_BPS,
+            threshold_bps: DEFAULT_THRESHOLD_BPS,
+            yes_votes,
+            no_votes,
+            status: ProposalStatus::Open,
+            resolved_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_config_validation() {
+        assert!(GovernanceConfig::default().is_valid());
+
+        let config = GovernanceConfig {
+            quorum_bps: 0,
+            ..Default::default()
+        };
+        assert!(!config.is_valid());
+
+        let config = GovernanceConfig {
+            threshold_bps: BPS_DENOMINATOR + 1,
+            ..Default::default()
+        };
+        assert!(!config.is_valid());
+
+        let config = GovernanceConfig {
+            voting_period: 0,
+            ..Default::default()
+        };
+        assert!(!config.is_valid());
+    }
+
+    #[test]
+    fn test_voting_window() {
+        let env = Env::default();
+        let mut p = proposal(&env, 3, 0, 0);
+
+        assert!(p.is_voting_open(1500));
+        assert!(p.is_voting_open(2000));
+        assert!(!p.is_voting_open(2001));
+
+        p.status = ProposalStatus::Executed;
+        assert!(!p.is_voting_open(1500));
+    }
+
+    #[test]
+    fn test_has_passed() {
+        let env = Env::default();
+
+        // 2 of 4 voted (quorum met), both in favor
+        assert!(proposal(&env, 4, 2, 0).has_passed());
+        // Split vote does not meet a strict majority
+        assert!(!proposal(&env, 4, 1, 1).has_passed());
+        // 1 of 4 voted: quorum not met
+        assert!(!proposal(&env, 4, 1, 0).has_passed());
+        // No votes at all
+        assert!(!proposal(&env, 4, 0, 0).has_passed());
+    }
+
+    #[test]
+    fn test_decided_outcome() {
+        let env = Env::default();
+
+        // 3 of 5 in favor: majority of all eligible voters
+        assert_eq!(proposal(&env, 5, 3, 0).decided_outcome(), Some(true));
+        // 3 of 5 against: can no longer reach a majority
+        assert_eq!(proposal(&env, 5, 0, 3).decided_outcome(), Some(false));
+        // 2 in favor, 1 against out of 5: still open
+        assert_eq!(proposal(&env, 5, 2, 1).decided_outcome(), None);
+        // Everyone voted
+        assert_eq!(proposal(&env, 2, 1, 1).decided_outcome(), Some(false));
+        assert_eq!(proposal(&env, 2, 2, 0).decided_outcome(), Some(true));
+    }
+}
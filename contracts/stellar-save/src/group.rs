@@ -1,3 +1,4 @@
+use crate::schedule::CycleSchedule;
 use core::fmt;
 use soroban_sdk::{contracttype, Address};
 
@@ -85,6 +86,18 @@ impl GroupStatus {
     pub fn is_terminal(&self) -> bool {
         matches!(self, GroupStatus::Completed | GroupStatus::Cancelled)
     }
+
+    /// Returns the numeric code used for this status in events
+    /// (0 = Pending, 1 = Active, 2 = Paused, 3 = Completed, 4 = Cancelled).
+    pub fn as_u32(&self) -> u32 {
+        match self {
+            GroupStatus::Pending => 0,
+            GroupStatus::Active => 1,
+            GroupStatus::Paused => 2,
+            GroupStatus::Completed => 3,
+            GroupStatus::Cancelled => 4,
+        }
+    }
 }
 
 impl fmt::Display for GroupStatus {
@@ -153,8 +166,9 @@ pub struct Group {
     /// - Group encounters an error state
     pub is_active: bool,
 
-    /// Status of the group (Active or Completed).
-    /// Replaces is_active for more explicit state management.
+    /// Status of the group. Starts as Pending and becomes Active once the
+    /// group is activated. Replaces is_active for more explicit state
+    /// management.
     pub status: GroupStatus,
 
     /// Timestamp when the group was created (Unix timestamp in seconds).
@@ -168,6 +182,14 @@ pub struct Group {
     /// Used for tracking when the first cycle started.
     /// Only set when started is true.
     pub started_at: u64,
+
+    /// How cycle deadlines are laid out after `started_at`.
+    /// Defaults to `FixedSeconds`, i.e. every `cycle_duration` seconds.
+    pub schedule: CycleSchedule,
+
+    /// Extra seconds added to the current cycle's deadline, granted by a
+    /// governance vote. Reset when the group advances to the next cycle.
+    pub deadline_extension: u64,
 }
 
 impl Group {
@@ -228,11 +250,13 @@ impl Group {
             min_members,
             member_count: 0,
             current_cycle: 0,
-            is_active: true,
-            status: GroupStatus::Active,
+            is_active: false,
+            status: GroupStatus::Pending,
             created_at,
             started: false,
             started_at: 0,
+            schedule: CycleSchedule::FixedSeconds,
+            deadline_extension: 0,
         }
     }
 
@@ -245,54 +269,31 @@ impl Group {
 
     /// Marks the group as completed.
     /// This should be called after verifying all payouts have been made.
-    /// Emits a GroupEvent::Completed event.
-    ///
-    /// # Arguments
-    /// * `env` - Soroban environment for event emission
+    /// The contract emits `GroupCompleted` once the status change is stored.
     ///
     /// # Panics
     /// Panics if the group is already complete.
-    pub fn complete(&mut self, env: &soroban_sdk::Env) {
+    pub fn complete(&mut self) {
         assert!(!self.is_complete(), "group is already complete");
         self.status = GroupStatus::Completed;
         self.is_active = false;
-
-        // Emit completion event
-        Self::emit_completed_event(env, self.id);
-    }
-
-    /// Emits a GroupEvent::Completed event.
-    ///
-    /// # Arguments
-    /// * `env` - Soroban environment
-    /// * `group_id` - The ID of the completed group
-    fn emit_completed_event(env: &soroban_sdk::Env, group_id: u64) {
-        env.events().publish(
-            (soroban_sdk::Symbol::new(env, "group_completed"),),
-            group_id,
-        );
     }
 
     /// Advances to the next cycle.
-    /// Should be called after a successful payout.
-    /// Emits a GroupEvent::Completed event when the group becomes complete.
-    ///
-    /// # Arguments
-    /// * `env` - Soroban environment for event emission
+    /// Should be called after a successful payout. Marks the group as
+    /// completed after its final cycle.
     ///
     /// # Panics
     /// Panics if the group is already complete.
-    pub fn advance_cycle(&mut self, env: &soroban_sdk::Env) {
+    pub fn advance_cycle(&mut self) {
         assert!(!self.is_complete(), "group is already complete");
         self.current_cycle += 1;
+        self.deadline_extension = 0;
 
         // Mark as complete if we've reached the final cycle
         if self.is_complete() {
             self.status = GroupStatus::Completed;
             self.is_active = false;
-
-            // Emit completion event
-            Self::emit_completed_event(env, self.id);
         }
     }
 
@@ -311,7 +312,8 @@ impl Group {
         self.status = GroupStatus::Active;
     }
 
-    /// Activates the group (starts the first cycle) once minimum members have joined.
+    /// Activates the group (starts the first cycle) once minimum members have joined,
+    /// moving it from Pending to Active.
     ///
     /// # Arguments
     /// * `timestamp` - Current timestamp when activation occurs
@@ -334,6 +336,24 @@ impl Group {
 
         self.started = true;
         self.started_at = timestamp;
+        self.is_active = true;
+        self.status = GroupStatus::Active;
+    }
+
+    /// Returns the deadline (end timestamp) of the given cycle according to
+    /// the group's schedule, or `None` if the calculation overflows.
+    ///
+    /// Any `deadline_extension` applies to the current cycle only.
+    /// Only meaningful once the group has started.
+    pub fn cycle_deadline(&self, cycle: u32) -> Option<u64> {
+        let deadline = self
+            .schedule
+            .cycle_deadline(self.started_at, self.cycle_duration, cycle)?;
+        if cycle == self.current_cycle {
+            deadline.checked_add(self.deadline_extension)
+        } else {
+            Some(deadline)
+        }
     }
 
     /// Checks if the group has met the minimum member requirement for activation.
@@ -367,7 +387,10 @@ impl Group {
 
 #[cfg(test)]
 mod tests {
+    extern crate std;
+
     use super::*;
+    use proptest::prelude::*;
     use soroban_sdk::{testutils::Address as _, Address, Env};
 
     #[test]
@@ -393,8 +416,8 @@ mod tests {
         assert_eq!(group.min_members, 2);
         assert_eq!(group.member_count, 0);
         assert_eq!(group.current_cycle, 0);
-        assert_eq!(group.is_active, true);
-        assert_eq!(group.status, GroupStatus::Active);
+        assert!(!group.is_active);
+        assert_eq!(group.status, GroupStatus::Pending);
         assert_eq!(group.created_at, 1234567890);
     }
 
@@ -465,22 +488,25 @@ mod tests {
         let creator = Address::generate(&env);
 
         let mut group = Group::new(1, creator, 10_000_000, 604800, 3, 2, 1234567890);
+        group.add_member();
+        group.add_member();
+        group.activate(1234567890);
 
         assert_eq!(group.current_cycle, 0);
         assert!(group.is_active);
         assert_eq!(group.status, GroupStatus::Active);
 
-        group.advance_cycle(&env);
+        group.advance_cycle();
         assert_eq!(group.current_cycle, 1);
         assert!(group.is_active);
         assert_eq!(group.status, GroupStatus::Active);
 
-        group.advance_cycle(&env);
+        group.advance_cycle();
         assert_eq!(group.current_cycle, 2);
         assert!(group.is_active);
         assert_eq!(group.status, GroupStatus::Active);
 
-        group.advance_cycle(&env);
+        group.advance_cycle();
         assert_eq!(group.current_cycle, 3);
         assert!(!group.is_active); // Auto-deactivated when complete
         assert_eq!(group.status, GroupStatus::Completed); // Status set to Completed
@@ -495,7 +521,7 @@ mod tests {
         let mut group = Group::new(1, creator, 10_000_000, 604800, 2, 2, 1234567890);
         group.current_cycle = 2;
 
-        group.advance_cycle(&env); // Should panic
+        group.advance_cycle(); // Should panic
     }
 
     #[test]
@@ -504,6 +530,9 @@ mod tests {
         let creator = Address::generate(&env);
 
         let mut group = Group::new(1, creator, 10_000_000, 604800, 3, 2, 1234567890);
+        group.add_member();
+        group.add_member();
+        group.activate(1234567890);
 
         assert!(group.is_active);
         assert_eq!(group.status, GroupStatus::Active);
@@ -524,13 +553,17 @@ mod tests {
 
         let mut group = Group::new(1, creator, 10_000_000, 604800, 3, 2, 1234567890);
 
-        // Group starts as Active
+        // Group starts as Pending and becomes Active on activation
+        assert_eq!(group.status, GroupStatus::Pending);
+        group.add_member();
+        group.add_member();
+        group.activate(1234567890);
         assert_eq!(group.status, GroupStatus::Active);
         assert!(group.is_active);
         assert!(!group.is_complete());
 
         // Complete the group manually
-        group.complete(&env);
+        group.complete();
 
         // Verify group is marked as completed
         assert_eq!(group.status, GroupStatus::Completed);
@@ -547,7 +580,7 @@ mod tests {
         let mut group = Group::new(1, creator, 10_000_000, 604800, 2, 2, 1234567890);
         group.current_cycle = 2; // Already complete via cycle advancement
 
-        group.complete(&env); // Should panic
+        group.complete(); // Should panic
     }
 
     #[test]
@@ -575,9 +608,9 @@ mod tests {
         let mut group = Group::new(1, creator, 10_000_000, 604800, 3, 2, 1234567890);
 
         // Advance through all cycles
-        group.advance_cycle(&env); // cycle 1
-        group.advance_cycle(&env); // cycle 2
-        group.advance_cycle(&env); // cycle 3 - complete
+        group.advance_cycle(); // cycle 1
+        group.advance_cycle(); // cycle 2
+        group.advance_cycle(); // cycle 3 - complete
 
         // Verify group is complete
         assert!(group.is_complete());
@@ -682,4 +715,102 @@ mod tests {
         assert!(GroupStatus::Completed.is_terminal());
         assert!(GroupStatus::Cancelled.is_terminal());
     }
+
+    #[test]
+    fn test_cycle_deadline_follows_schedule() {
+        let env = Env::default();
+        let creator = Address::generate(&env);
+        let mut group = Group::new(1, creator, 10_000_000, 604800, 5, 2, 1000);
+        group.add_member();
+        group.add_member();
+        group.activate(1000);
+
+        assert_eq!(group.schedule, CycleSchedule::FixedSeconds);
+        assert_eq!(group.cycle_deadline(0), Some(1000 + 604800));
+        assert_eq!(group.cycle_deadline(2), Some(1000 + 604800 * 3));
+
+        // Weekly on Monday: 1970-01-05 is the first Monday after the start
+        group.schedule = CycleSchedule::Weekly(0);
+        assert_eq!(group.cycle_deadline(0), Some(4 * 86400));
+        assert_eq!(group.cycle_deadline(1), Some(11 * 86400));
+    }
+
+    #[test]
+    fn test_deadline_extension_applies_to_current_cycle() {
+        let env = Env::default();
+        let creator = Address::generate(&env);
+        let mut group = Group::new(1, creator, 10_000_000, 3600, 3, 2, 1000);
+        group.add_member();
+        group.add_member();
+        group.activate(1000);
+
+        group.deadline_extension = 600;
+        assert_eq!(group.cycle_deadline(0), Some(1000 + 3600 + 600));
+        assert_eq!(group.cycle_deadline(1), Some(1000 + 7200));
+
+        // Advancing clears the extension
+        group.advance_cycle();
+        assert_eq!(group.deadline_extension, 0);
+        assert_eq!(group.cycle_deadline(1), Some(1000 + 7200));
+    }
+
+    #[test]
+    fn test_group_status_codes() {
+        assert_eq!(GroupStatus::Pending.as_u32(), 0);
+        assert_eq!(GroupStatus::Active.as_u32(), 1);
+        assert_eq!(GroupStatus::Paused.as_u32(), 2);
+        assert_eq!(GroupStatus::Completed.as_u32(), 3);
+        assert_eq!(GroupStatus::Cancelled.as_u32(), 4);
+    }
+
+    fn status() -> impl Strategy<Value = GroupStatus> {
+        prop_oneof![
+            Just(GroupStatus::Pending),
+            Just(GroupStatus::Active),
+            Just(GroupStatus::Paused),
+            Just(GroupStatus::Completed),
+            Just(GroupStatus::Cancelled),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn test_terminal_statuses_never_transition(from in status(), to in status()) {
+            if from.is_terminal() && from != to {
+                prop_assert!(!from.can_transition_to(&to));
+            }
+            prop_assert!(from.can_transition_to(&from));
+        }
+
+        #[test]
+        fn test_lifecycle_completes_after_max_members_cycles(
+            contribution_amount in 1i128..=i128::MAX / 64,
+            cycle_duration in 1u64..=u64::MAX,
+            max_members in 2u32..=32,
+            started_at in 0u64..=u64::MAX,
+        ) {
+            let env = Env::default();
+            let creator = Address::generate(&env);
+            let mut group =
+                Group::new(1, creator, contribution_amount, cycle_duration, max_members, 2, 0);
+            for _ in 0..max_members {
+                group.add_member();
+            }
+            group.activate(started_at);
+
+            for cycle in 0..max_members {
+                prop_assert!(group.validate());
+                prop_assert!(!group.is_complete());
+                prop_assert_eq!(group.current_cycle, cycle);
+                // Overflowing deadlines come back as None rather than panicking
+                let _ = group.cycle_deadline(cycle);
+                group.advance_cycle();
+            }
+
+            prop_assert!(group.validate());
+            prop_assert!(group.is_complete());
+            prop_assert_eq!(group.status, GroupStatus::Completed);
+            prop_assert!(!group.is_active);
+        }
+    }
 }
@@ -51,10 +51,17 @@ pub fn format_group_id(env: &Env, group_id: u64) -> String {
         result.push_back(digits.get(i).unwrap());
     }
     
-    String::from_bytes(env, &result)
+    // "GROUP-" plus at most 20 digits for u64::MAX
+    let len = result.len() as usize;
+    let mut buf = [0u8; 26];
+    result.copy_into_slice(&mut buf[..len]);
+    String::from_bytes(env, &buf[..len])
 }
 
 /// Checks if the current cycle deadline has passed.
+///
+/// The deadline follows the group's cycle schedule, so calendar-aligned
+/// groups are checked against their weekday or day-of-month boundary.
 /// 
 /// # Arguments
 /// * `group` - The group to check
@@ -72,15 +79,18 @@ pub fn is_cycle_deadline_passed(group: &Group, current_time: u64) -> bool {
         return false;
     }
     
-    let cycle_deadline = group.started_at + (group.cycle_duration * (group.current_cycle as u64 + 1));
-    current_time > cycle_deadline
+    match group.cycle_deadline(group.current_cycle) {
+        Some(cycle_deadline) => current_time > cycle_deadline,
+        // A deadline beyond u64::MAX can never have passed
+        None => false,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::{Env, Address};
-    use crate::group::GroupStatus;
+    use soroban_sdk::{testutils::Address as _, Env, Address};
+    use crate::schedule::{CycleSchedule, SECONDS_PER_DAY};
 
     #[test]
     fn test_format_group_id_single_digit() {
@@ -125,6 +135,8 @@ mod tests {
         let env = Env::default();
         let creator = Address::generate(&env);
         let mut group = Group::new(1, creator, 1000000, 604800, 5, 2, 1000);
+        group.add_member();
+        group.add_member();
         group.activate(1000);
         
         // Current time before deadline (started_at + cycle_duration)
@@ -136,6 +148,8 @@ mod tests {
         let env = Env::default();
         let creator = Address::generate(&env);
         let mut group = Group::new(1, creator, 1000000, 604800, 5, 2, 1000);
+        group.add_member();
+        group.add_member();
         group.activate(1000);
         
         // Current time after deadline
@@ -147,11 +161,31 @@ mod tests {
         let env = Env::default();
         let creator = Address::generate(&env);
         let mut group = Group::new(1, creator, 1000000, 604800, 5, 2, 1000);
+        group.add_member();
+        group.add_member();
         group.activate(1000);
-        group.advance_cycle(&env);
+        group.advance_cycle();
         
         // Deadline for cycle 1 is started_at + (cycle_duration * 2)
         assert!(!is_cycle_deadline_passed(&group, 1000 + 604800 * 2));
         assert!(is_cycle_deadline_passed(&group, 1000 + 604800 * 2 + 1));
     }
+
+    #[test]
+    fn test_is_cycle_deadline_passed_monthly_schedule() {
+        let env = Env::default();
+        let creator = Address::generate(&env);
+        let mut group = Group::new(1, creator, 1000000, 2592000, 5, 2, 1000);
+        group.schedule = CycleSchedule::Monthly(1);
+
+        // Start 2024-01-10; first deadline is 2024-02-01 00:00 UTC
+        let jan_10_2024 = 1704844800;
+        let feb_1_2024 = jan_10_2024 + 22 * SECONDS_PER_DAY;
+        group.add_member();
+        group.add_member();
+        group.activate(jan_10_2024);
+
+        assert!(!is_cycle_deadline_passed(&group, feb_1_2024));
+        assert!(is_cycle_deadline_passed(&group, feb_1_2024 + 1));
+    }
 }
@@ -0,0 +1,50 @@
+use crate::destination::PAYOUT_BPS_TOTAL;
+use crate::error::StellarSaveError;
+
+/// Highest insurance levy a group can declare, in basis points (10%).
+pub const MAX_INSURANCE_LEVY_BPS: u32 = 1_000;
+
+/// Returns the insurance levy of `levy_bps` basis points added to a
+/// contribution of `contribution`, rounded down.
+///
+/// # Errors
+/// `StellarSaveError::Overflow` if `contribution` is too large to levy.
+pub fn insurance_levy(contribution: i128, levy_bps: u32) -> Result<i128, StellarSaveError> {
+    contribution
+        .checked_mul(levy_bps as i128)
+        .map(|scaled| scaled / PAYOUT_BPS_TOTAL as i128)
+        .ok_or(StellarSaveError::Overflow)
+}
+
+/// Returns a member's pro rata share of an unused `reserve`, given the
+/// levies they paid out of `total_paid` by all members, rounded down.
+pub fn refund_share(reserve: i128, paid: i128, total_paid: i128) -> i128 {
+    if total_paid <= 0 {
+        return 0;
+    }
+    reserve * paid / total_paid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insurance_levy() {
+        assert_eq!(insurance_levy(1_000, 0), Ok(0));
+        assert_eq!(insurance_levy(1_000, 200), Ok(20));
+        assert_eq!(insurance_levy(999, 100), Ok(9));
+        assert_eq!(insurance_levy(1_000, MAX_INSURANCE_LEVY_BPS), Ok(100));
+        assert_eq!(
+            insurance_levy(i128::MAX, MAX_INSURANCE_LEVY_BPS),
+            Err(StellarSaveError::Overflow)
+        );
+    }
+
+    #[test]
+    fn test_refund_share() {
+        assert_eq!(refund_share(90, 20, 60), 30);
+        assert_eq!(refund_share(100, 1, 3), 33);
+        assert_eq!(refund_share(100, 0, 0), 0);
+    }
+}
@@ -0,0 +1,139 @@
+use soroban_sdk::{contracttype, Address, BytesN};
+
+/// Join policy controlling who may join a group while it is Pending.
+///
+/// Groups default to `Open`, which preserves the original behavior where any
+/// address can join until the group is full. `InviteOnly` groups require the
+/// joiner to either be on the group's allowlist or present a valid one-time
+/// invite code.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum JoinPolicy {
+    /// Any address can join while the group is Pending and not full.
+    Open,
+
+    /// Only allowlisted addresses or holders of a valid invite code can join.
+    InviteOnly,
+
+    /// Prospective members submit a join request that an admin approves or
+    /// rejects. Allowlisted addresses and invite holders can still join directly.
+    ApprovalRequired,
+}
+
+/// One-time invite issued by a group admin.
+///
+/// Only the SHA-256 hash of the invite code is stored on-chain. The issuer
+/// shares the plain code off-chain, and the joiner presents it when joining.
+/// An invite is consumed (removed from storage) as soon as it is used.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Invite {
+    /// ID of the group this invite grants access to.
+    pub group_id: u64,
+
+    /// SHA-256 hash of the invite code.
+    pub code_hash: BytesN<32>,
+
+    /// Address that issued the invite (a group admin).
+    pub issued_by: Address,
+
+    /// Timestamp when the invite was issued (Unix timestamp in seconds).
+    pub issued_at: u64,
+
+    /// Timestamp after which the invite can no longer be used.
+    /// A value of 0 means the invite never expires.
+    pub expires_at: u64,
+}
+
+impl Invite {
+    /// Creates a new Invite.
+    ///
+    /// # Arguments
+    /// * `group_id` - ID of the group the invite is for
+    /// * `code_hash` - SHA-256 hash of the invite code
+    /// * `issued_by` - Address issuing the invite
+    /// * `issued_at` - Issue timestamp
+    /// * `expires_at` - Expiry timestamp (0 for no expiry)
+    ///
+    /// # Panics
+    /// Panics if `expires_at` is non-zero and not after `issued_at`.
+    pub fn new(
+        group_id: u64,
+        code_hash: BytesN<32>,
+        issued_by: Address,
+        issued_at: u64,
+        expires_at: u64,
+    ) -> Self {
+        assert!(
+            expires_at == 0 || expires_at > issued_at,
+            "expires_at must be after issued_at"
+        );
+
+        Self {
+            group_id,
+            code_hash,
+            issued_by,
+            issued_at,
+            expires_at,
+        }
+    }
+
+    /// Checks if the invite has expired at the given time.
+    pub fn is_expired(&self, current_time: u64) -> bool {
+        self.expires_at != 0 && current_time > self.expires_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    #[test]
+    fn test_invite_creation() {
+        let env = Env::default();
+        let creator = Address::generate(&env);
+        let hash = BytesN::from_array(&env, &[7u8; 32]);
+
+        let invite = Invite::new(1, hash.clone(), creator.clone(), 1000, 2000);
+
+        assert_eq!(invite.group_id, 1);
+        assert_eq!(invite.code_hash, hash);
+        assert_eq!(invite.issued_by, creator);
+        assert_eq!(invite.expires_at, 2000);
+    }
+
+    #[test]
+    fn test_invite_expiry() {
+        let env = Env::default();
+        let creator = Address::generate(&env);
+        let hash = BytesN::from_array(&env, &[7u8; 32]);
+
+        let invite = Invite::new(1, hash, creator, 1000, 2000);
+
+        assert!(!invite.is_expired(1500));
+        assert!(!invite.is_expired(2000));
+        assert!(invite.is_expired(2001));
+    }
+
+    #[test]
+    fn test_invite_without_expiry() {
+        let env = Env::default();
+        let creator = Address::generate(&env);
+        let hash = BytesN::from_array(&env, &[7u8; 32]);
+
+        let invite = Invite::new(1, hash, creator, 1000, 0);
+
+        assert!(!invite.is_expired(u64::MAX));
+    }
+
+    #[test]
+    #[should_panic(expected = "expires_at must be after issued_at")]
+    fn test_invite_invalid_expiry() {
+        let env = Env::default();
+        let creator = Address::generate(&env);
+        let hash = BytesN::from_array(&env, &[7u8; 32]);
+
+        Invite::new(1, hash, creator, 1000, 500);
+    }
+}
@@ -0,0 +1,97 @@
+use soroban_sdk::{contracttype, Address, BytesN};
+
+/// How long a join request stays pending before it expires (7 days).
+pub const JOIN_REQUEST_TTL: u64 = 604800;
+
+/// A pending request to join a group that requires admin approval.
+///
+/// The request carries the hash of an off-chain message (for example an
+/// introduction or a reference from an existing member) so an admin can
+/// verify it without the message itself being published on-chain.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JoinRequest {
+    /// ID of the group the request is for.
+    pub group_id: u64,
+
+    /// Address asking to join.
+    pub member: Address,
+
+    /// Hash of the off-chain message accompanying the request.
+    pub message_hash: BytesN<32>,
+
+    /// Timestamp when the request was submitted (Unix timestamp in seconds).
+    pub requested_at: u64,
+
+    /// Timestamp after which the request is considered stale.
+    pub expires_at: u64,
+}
+
+impl JoinRequest {
+    /// Creates a new JoinRequest that expires `JOIN_REQUEST_TTL` seconds
+    /// after `requested_at`.
+    pub fn new(
+        group_id: u64,
+        member: Address,
+        message_hash: BytesN<32>,
+        requested_at: u64,
+    ) -> Self {
+        Self {
+            group_id,
+            member,
+            message_hash,
+            requested_at,
+            expires_at: requested_at.saturating_add(JOIN_REQUEST_TTL),
+        }
+    }
+
+    /// Checks if the request has expired at the given time.
+    pub fn is_expired(&self, current_time: u64) -> bool {
+        current_time > self.expires_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Address, Env};
+
+    #[test]
+    fn test_join_request_creation() {
+        let env = Env::default();
+        let member = Address::generate(&env);
+        let message_hash = BytesN::from_array(&env, &[3u8; 32]);
+
+        let request = JoinRequest::new(1, member.clone(), message_hash.clone(), 1000);
+
+        assert_eq!(request.group_id, 1);
+        assert_eq!(request.member, member);
+        assert_eq!(request.message_hash, message_hash);
+        assert_eq!(request.requested_at, 1000);
+        assert_eq!(request.expires_at, 1000 + JOIN_REQUEST_TTL);
+    }
+
+    #[test]
+    fn test_join_request_expiry() {
+        let env = Env::default();
+        let member = Address::generate(&env);
+        let message_hash = BytesN::from_array(&env, &[3u8; 32]);
+
+        let request = JoinRequest::new(1, member, message_hash, 1000);
+
+        assert!(!request.is_expired(1000 + JOIN_REQUEST_TTL));
+        assert!(request.is_expired(1000 + JOIN_REQUEST_TTL + 1));
+    }
+
+    #[test]
+    fn test_join_request_expiry_saturates() {
+        let env = Env::default();
+        let member = Address::generate(&env);
+        let message_hash = BytesN::from_array(&env, &[3u8; 32]);
+
+        let request = JoinRequest::new(1, member, message_hash, u64::MAX - 1);
+
+        assert_eq!(request.expires_at, u64::MAX);
+        assert!(!request.is_expired(u64::MAX));
+    }
+}
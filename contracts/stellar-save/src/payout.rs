@@ -1,4 +1,5 @@
-use soroban_sdk::{contracttype, Address};
+use crate::destination::PayoutTransfer;
+use soroban_sdk::{contracttype, Address, Vec};
 
 /// Payout Record structure for tracking payout events in rotational savings groups.
 ///
@@ -28,6 +29,16 @@ pub struct PayoutRecord {
     /// Timestamp when the payout was executed (Unix timestamp in seconds).
     /// Used for tracking payout timing and audit purposes.
     pub timestamp: u64,
+
+    /// Where the funds were actually sent, if the recipient set a payout
+    /// destination. `None` means the full amount went to `recipient`.
+    pub transfers: Option<Vec<PayoutTransfer>>,
+
+    /// Protocol fee deducted from `amount`, in stroops.
+    pub protocol_fee: i128,
+
+    /// Organizer fee deducted from `amount`, in stroops.
+    pub organizer_fee: i128,
 }
 
 impl PayoutRecord {
@@ -59,9 +70,30 @@ impl PayoutRecord {
             cycle_number,
             amount,
             timestamp,
+            transfers: None,
+            protocol_fee: 0,
+            organizer_fee: 0,
         }
     }
 
+    /// Records the transfers made to the recipient's payout destinations.
+    pub fn with_transfers(mut self, transfers: Vec<PayoutTransfer>) -> Self {
+        self.transfers = Some(transfers);
+        self
+    }
+
+    /// Records the fees deducted from the payout.
+    pub fn with_fees(mut self, protocol_fee: i128, organizer_fee: i128) -> Self {
+        self.protocol_fee = protocol_fee;
+        self.organizer_fee = organizer_fee;
+        self
+    }
+
+    /// Returns the amount left for the recipient after fees.
+    pub fn net_amount(&self) -> i128 {
+        self.amount - self.protocol_fee - self.organizer_fee
+    }
+
     /// Validates that the payout record is sound.
     /// Returns true if all constraints are met.
     pub fn validate(&self) -> bool {
@@ -123,6 +155,27 @@ mod tests {
         assert_eq!(payout.cycle_number, 0);
         assert_eq!(payout.amount, 50_000_000);
         assert_eq!(payout.timestamp, 1234567890);
+        assert_eq!(payout.transfers, None);
+    }
+
+    #[test]
+    fn test_payout_record_with_transfers() {
+        let env = Env::default();
+        let recipient = Address::generate(&env);
+        let landlord = Address::generate(&env);
+
+        let transfers = Vec::from_array(
+            &env,
+            [PayoutTransfer {
+                destination: landlord,
+                amount: 50_000_000,
+            }],
+        );
+        let payout = PayoutRecord::new(recipient.clone(), 1, 0, 50_000_000, 1234567890)
+            .with_transfers(transfers.clone());
+
+        assert_eq!(payout.recipient, recipient);
+        assert_eq!(payout.transfers, Some(transfers));
     }
 
     #[test]
@@ -229,4 +282,16 @@ mod tests {
         assert_eq!(payout_cycle_0.recipient, payout_cycle_1.recipient);
         assert_eq!(payout_cycle_0.cycle_number + 1, payout_cycle_1.cycle_number);
     }
+
+    #[test]
+    fn test_payout_record_fees() {
+        let env = Env::default();
+        let payout = PayoutRecord::new(Address::generate(&env), 1, 0, 10_000, 1234567890);
+        assert_eq!(payout.net_amount(), 10_000);
+
+        let payout = payout.with_fees(100, 50);
+        assert_eq!(payout.protocol_fee, 100);
+        assert_eq!(payout.organizer_fee, 50);
+        assert_eq!(payout.net_amount(), 9_850);
+    }
 }
@@ -1,4 +1,5 @@
 use crate::error::StellarSaveError;
+use crate::shares::{DEFAULT_SHARE_UNITS, UNITS_PER_SHARE};
 use crate::storage::StorageKeyBuilder;
 use soroban_sdk::{contracttype, Env};
 
@@ -11,7 +12,8 @@ use soroban_sdk::{contracttype, Env};
 /// - Pool return amount calculations
 ///
 /// The pool represents the total funds available for distribution in a cycle,
-/// calculated as: pool_amount = contribution_amount × member_count
+/// calculated as: pool_amount = contribution_amount × total shares. When every
+/// member holds one share this is contribution_amount × member_count.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PoolInfo {
@@ -24,10 +26,13 @@ pub struct PoolInfo {
     /// Total number of members in the group
     pub member_count: u32,
 
-    /// Fixed contribution amount per member in stroops
+    /// Fixed contribution amount per share in stroops
     pub contribution_amount: i128,
 
-    /// Total pool amount (contribution_amount × member_count)
+    /// Total share units held by members (2 units = 1 share)
+    pub total_share_units: u32,
+
+    /// Total pool amount (contribution_amount × total shares)
     pub total_pool_amount: i128,
 
     /// Total amount contributed so far in this cycle
@@ -38,6 +43,13 @@ pub struct PoolInfo {
 
     /// Whether the cycle is complete (all members have contributed)
     pub is_cycle_complete: bool,
+
+    /// Installments paid this cycle by members who have not paid in full yet.
+    /// Not included in `current_contributions`.
+    pub partial_contributions: i128,
+
+    /// Number of members who have paid part, but not all, of this cycle
+    pub partial_contributors: u32,
 }
 
 impl PoolInfo {
@@ -110,6 +122,63 @@ impl PoolCalculator {
         Ok(pool_amount)
     }
 
+    /// Calculates the pool for a cycle from the total share units held.
+    ///
+    /// Formula: total_pool = contribution_amount × share_units / 2
+    ///
+    /// # Errors
+    /// - `InvalidAmount` if contribution_amount is <= 0
+    /// - `InvalidState` if total_share_units is 0
+    /// - `InternalError` if multiplication overflows
+    pub fn calculate_share_pool(
+        contribution_amount: i128,
+        total_share_units: u32,
+    ) -> Result<i128, StellarSaveError> {
+        if contribution_amount <= 0 {
+            return Err(StellarSaveError::InvalidAmount);
+        }
+
+        if total_share_units == 0 {
+            return Err(StellarSaveError::InvalidState);
+        }
+
+        let pool_amount = contribution_amount
+            .checked_mul(total_share_units as i128)
+            .ok_or(StellarSaveError::InternalError)?;
+
+        Ok(pool_amount / UNITS_PER_SHARE as i128)
+    }
+
+    /// Sums the share units held by the members of a group. Members without
+    /// an explicit share setting hold one share.
+    ///
+    /// # Returns
+    /// * `Ok(units)` - Total share units (2 units = 1 share)
+    /// * `Err(StellarSaveError)` - If group not found
+    pub fn get_total_share_units(env: &Env, group_id: u64) -> Result<u32, StellarSaveError> {
+        let members_key = StorageKeyBuilder::group_members(group_id);
+        let members: soroban_sdk::Vec<soroban_sdk::Address> = env
+            .storage()
+            .persistent()
+            .get(&members_key)
+            .ok_or(StellarSaveError::GroupNotFound)?;
+
+        let mut total: u32 = 0;
+        for member in members.iter() {
+            let shares_key = StorageKeyBuilder::member_shares(group_id, member);
+            let units: u32 = env
+                .storage()
+                .persistent()
+                .get(&shares_key)
+                .unwrap_or(DEFAULT_SHARE_UNITS);
+            total = total
+                .checked_add(units)
+                .ok_or(StellarSaveError::InternalError)?;
+        }
+
+        Ok(total)
+    }
+
     /// Retrieves the member count for a group from storage.
     ///
     /// # Arguments
@@ -194,6 +263,44 @@ impl PoolCalculator {
         Ok(count)
     }
 
+    /// Sums the installments paid toward a cycle by members who have not paid
+    /// it in full yet.
+    ///
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `group_id` - ID of the group
+    /// * `cycle` - Cycle number
+    ///
+    /// # Returns
+    /// * `Ok((amount, members))` - Total installments and number of members who paid them
+    pub fn get_cycle_partial_contributions(
+        env: &Env,
+        group_id: u64,
+        cycle: u32,
+    ) -> Result<(i128, u32), StellarSaveError> {
+        let members_key = StorageKeyBuilder::group_members(group_id);
+        let members: soroban_sdk::Vec<soroban_sdk::Address> = env
+            .storage()
+            .persistent()
+            .get(&members_key)
+            .ok_or(StellarSaveError::GroupNotFound)?;
+
+        let mut amount: i128 = 0;
+        let mut count: u32 = 0;
+        for member in members.iter() {
+            let installments_key =
+                StorageKeyBuilder::contribution_installments(group_id, cycle, member);
+            if let Some(paid) = env.storage().persistent().get::<_, i128>(&installments_key) {
+                amount = amount
+                    .checked_add(paid)
+                    .ok_or(StellarSaveError::InternalError)?;
+                count += 1;
+            }
+        }
+
+        Ok((amount, count))
+    }
+
     /// Builds complete pool information for a group and cycle.
     ///
     /// This is the primary function for getting comprehensive pool data.
@@ -218,8 +325,9 @@ impl PoolCalculator {
         // Get contribution amount
         let contribution_amount = Self::get_contribution_amount(env, group_id)?;
 
-        // Calculate total pool
-        let total_pool_amount = Self::calculate_total_pool(contribution_amount, member_count)?;
+        // Calculate total pool from the shares held
+        let total_share_units = Self::get_total_share_units(env, group_id)?;
+        let total_pool_amount = Self::calculate_share_pool(contribution_amount, total_share_units)?;
 
         // Get current cycle contributions
         let current_contributions = Self::get_cycle_contributions_total(env, group_id, cycle)?;
@@ -230,15 +338,22 @@ impl PoolCalculator {
         // Determine if cycle is complete
         let is_cycle_complete = contributors_count >= member_count;
 
+        // Get installments from members who have not paid in full
+        let (partial_contributions, partial_contributors) =
+            Self::get_cycle_partial_contributions(env, group_id, cycle)?;
+
         Ok(PoolInfo {
             group_id,
             cycle,
             member_count,
             contribution_amount,
+            total_share_units,
             total_pool_amount,
             current_contributions,
             contributors_count,
             is_cycle_complete,
+            partial_contributions,
+            partial_contributors,
         })
     }
 
@@ -297,7 +412,10 @@ impl PoolCalculator {
 
 #[cfg(test)]
 mod tests {
+    extern crate std;
+
     use super::*;
+    use proptest::prelude::*;
 
     #[test]
     fn test_calculate_total_pool_valid() {
@@ -310,6 +428,18 @@ mod tests {
         assert_eq!(result.unwrap(), 10_000_000i128);
     }
 
+    #[test]
+    fn test_calculate_share_pool() {
+        // One double share, one share and two half shares: 4 shares in total
+        let result = PoolCalculator::calculate_share_pool(1_000_000, 4 + 2 + 1 + 1);
+        assert_eq!(result, Ok(4_000_000i128));
+
+        assert_eq!(
+            PoolCalculator::calculate_share_pool(1_000_000, 0),
+            Err(StellarSaveError::InvalidState)
+        );
+    }
+
     #[test]
     fn test_calculate_total_pool_single_member() {
         let contribution = 5_000_000i128;
@@ -383,10 +513,13 @@ mod tests {
             cycle: 0,
             member_count: 5,
             contribution_amount: 1_000_000i128,
+            total_share_units: 10,
             total_pool_amount: 5_000_000i128,
             current_contributions: 5_000_000i128,
             contributors_count: 5,
             is_cycle_complete: true,
+            partial_contributions: 0,
+            partial_contributors: 0,
         };
 
         assert_eq!(pool.return_amount(), 5_000_000i128);
@@ -399,10 +532,13 @@ mod tests {
             cycle: 0,
             member_count: 5,
             contribution_amount: 1_000_000i128,
+            total_share_units: 10,
             total_pool_amount: 5_000_000i128,
             current_contributions: 5_000_000i128,
             contributors_count: 5,
             is_cycle_complete: true,
+            partial_contributions: 0,
+            partial_contributors: 0,
         };
 
         assert!(pool.is_complete());
@@ -415,10 +551,13 @@ mod tests {
             cycle: 0,
             member_count: 5,
             contribution_amount: 1_000_000i128,
+            total_share_units: 10,
             total_pool_amount: 5_000_000i128,
             current_contributions: 3_000_000i128,
             contributors_count: 3,
             is_cycle_complete: false,
+            partial_contributions: 0,
+            partial_contributors: 0,
         };
 
         assert!(!pool.is_complete());
@@ -431,10 +570,13 @@ mod tests {
             cycle: 0,
             member_count: 5,
             contribution_amount: 1_000_000i128,
+            total_share_units: 10,
             total_pool_amount: 5_000_000i128,
             current_contributions: 3_000_000i128,
             contributors_count: 3,
             is_cycle_complete: false,
+            partial_contributions: 0,
+            partial_contributors: 0,
         };
 
         assert_eq!(pool.remaining_contributions_needed(), 2);
@@ -447,10 +589,13 @@ mod tests {
             cycle: 0,
             member_count: 5,
             contribution_amount: 1_000_000i128,
+            total_share_units: 10,
             total_pool_amount: 5_000_000i128,
             current_contributions: 5_000_000i128,
             contributors_count: 5,
             is_cycle_complete: true,
+            partial_contributions: 0,
+            partial_contributors: 0,
         };
 
         assert_eq!(pool.remaining_contributions_needed(), 0);
@@ -463,10 +608,13 @@ mod tests {
             cycle: 0,
             member_count: 5,
             contribution_amount: 1_000_000i128,
+            total_share_units: 10,
             total_pool_amount: 5_000_000i128,
             current_contributions: 0i128,
             contributors_count: 0,
             is_cycle_complete: false,
+            partial_contributions: 0,
+            partial_contributors: 0,
         };
 
         assert_eq!(pool.completion_percentage(), 0);
@@ -479,10 +627,13 @@ mod tests {
             cycle: 0,
             member_count: 10,
             contribution_amount: 1_000_000i128,
+            total_share_units: 20,
             total_pool_amount: 10_000_000i128,
             current_contributions: 5_000_000i128,
             contributors_count: 5,
             is_cycle_complete: false,
+            partial_contributions: 0,
+            partial_contributors: 0,
         };
 
         assert_eq!(pool.completion_percentage(), 50);
@@ -495,10 +646,13 @@ mod tests {
             cycle: 0,
             member_count: 5,
             contribution_amount: 1_000_000i128,
+            total_share_units: 10,
             total_pool_amount: 5_000_000i128,
             current_contributions: 5_000_000i128,
             contributors_count: 5,
             is_cycle_complete: true,
+            partial_contributions: 0,
+            partial_contributors: 0,
         };
 
         assert_eq!(pool.completion_percentage(), 100);
@@ -511,10 +665,13 @@ mod tests {
             cycle: 0,
             member_count: 3,
             contribution_amount: 1_000_000i128,
+            total_share_units: 6,
             total_pool_amount: 3_000_000i128,
             current_contributions: 1_000_000i128,
             contributors_count: 1,
             is_cycle_complete: false,
+            partial_contributions: 0,
+            partial_contributors: 0,
         };
 
         // 1/3 = 33.33%, should round down to 33
@@ -528,10 +685,13 @@ mod tests {
             cycle: 0,
             member_count: 5,
             contribution_amount: 1_000_000i128,
+            total_share_units: 10,
             total_pool_amount: 5_000_000i128,
             current_contributions: 5_000_000i128,
             contributors_count: 5,
             is_cycle_complete: true,
+            partial_contributions: 0,
+            partial_contributors: 0,
         };
 
         let result = PoolCalculator::validate_pool_ready_for_payout(&pool);
@@ -545,10 +705,13 @@ mod tests {
             cycle: 0,
             member_count: 5,
             contribution_amount: 1_000_000i128,
+            total_share_units: 10,
             total_pool_amount: 5_000_000i128,
             current_contributions: 3_000_000i128,
             contributors_count: 3,
             is_cycle_complete: false,
+            partial_contributions: 0,
+            partial_contributors: 0,
         };
 
         let result = PoolCalculator::validate_pool_ready_for_payout(&pool);
@@ -563,10 +726,13 @@ mod tests {
             cycle: 0,
             member_count: 5,
             contribution_amount: 1_000_000i128,
+            total_share_units: 10,
             total_pool_amount: 5_000_000i128,
             current_contributions: 4_500_000i128, // Mismatch!
             contributors_count: 5,
             is_cycle_complete: true,
+            partial_contributions: 0,
+            partial_contributors: 0,
         };
 
         let result = PoolCalculator::validate_pool_ready_for_payout(&pool);
@@ -581,10 +747,13 @@ mod tests {
             cycle: 0,
             member_count: 5,
             contribution_amount: 1_000_000i128,
+            total_share_units: 10,
             total_pool_amount: 5_000_000i128,
             current_contributions: 5_000_000i128,
             contributors_count: 5,
             is_cycle_complete: true,
+            partial_contributions: 0,
+            partial_contributors: 0,
         };
 
         let cloned = pool.clone();
@@ -598,10 +767,13 @@ mod tests {
             cycle: 0,
             member_count: 5,
             contribution_amount: 1_000_000i128,
+            total_share_units: 10,
             total_pool_amount: 5_000_000i128,
             current_contributions: 5_000_000i128,
             contributors_count: 5,
             is_cycle_complete: true,
+            partial_contributions: 0,
+            partial_contributors: 0,
         };
 
         let pool2 = PoolInfo {
@@ -609,10 +781,13 @@ mod tests {
             cycle: 0,
             member_count: 5,
             contribution_amount: 1_000_000i128,
+            total_share_units: 10,
             total_pool_amount: 5_000_000i128,
             current_contributions: 5_000_000i128,
             contributors_count: 5,
             is_cycle_complete: true,
+            partial_contributions: 0,
+            partial_contributors: 0,
         };
 
         assert_eq!(pool1, pool2);
@@ -625,10 +800,13 @@ mod tests {
             cycle: 0,
             member_count: 5,
             contribution_amount: 1_000_000i128,
+            total_share_units: 10,
             total_pool_amount: 5_000_000i128,
             current_contributions: 5_000_000i128,
             contributors_count: 5,
             is_cycle_complete: true,
+            partial_contributions: 0,
+            partial_contributors: 0,
         };
 
         let pool2 = PoolInfo {
@@ -636,10 +814,13 @@ mod tests {
             cycle: 0,
             member_count: 5,
             contribution_amount: 1_000_000i128,
+            total_share_units: 10,
             total_pool_amount: 5_000_000i128,
             current_contributions: 5_000_000i128,
             contributors_count: 5,
             is_cycle_complete: true,
+            partial_contributions: 0,
+            partial_contributors: 0,
         };
 
         assert_ne!(pool1, pool2);
@@ -687,4 +868,49 @@ mod tests {
         assert!(result.is_err());
         assert_eq!(result.unwrap_err(), StellarSaveError::InvalidAmount);
     }
+
+    proptest! {
+        #[test]
+        fn test_calculate_total_pool_matches_product(
+            contribution_amount in any::<i128>(),
+            member_count in any::<u32>(),
+        ) {
+            let result = PoolCalculator::calculate_total_pool(contribution_amount, member_count);
+            if contribution_amount <= 0 {
+                prop_assert_eq!(result, Err(StellarSaveError::InvalidAmount));
+            } else if member_count == 0 {
+                prop_assert_eq!(result, Err(StellarSaveError::InvalidState));
+            } else {
+                match contribution_amount.checked_mul(member_count as i128) {
+                    Some(pool) => prop_assert_eq!(result, Ok(pool)),
+                    None => prop_assert_eq!(result, Err(StellarSaveError::InternalError)),
+                }
+            }
+        }
+
+        #[test]
+        fn test_calculate_share_pool_halves_units(
+            contribution_amount in 1i128..=i128::MAX / u32::MAX as i128,
+            total_share_units in 1u32..=u32::MAX,
+        ) {
+            let pool = PoolCalculator::calculate_share_pool(contribution_amount, total_share_units)
+                .unwrap();
+            prop_assert!(pool >= 0);
+            prop_assert_eq!(
+                pool,
+                contribution_amount * total_share_units as i128 / UNITS_PER_SHARE as i128
+            );
+
+            // Whole shares pool exactly like one share per member
+            if total_share_units % UNITS_PER_SHARE == 0 {
+                prop_assert_eq!(
+                    Ok(pool),
+                    PoolCalculator::calculate_total_pool(
+                        contribution_amount,
+                        total_share_units / UNITS_PER_SHARE
+                    )
+                );
+            }
+        }
+    }
 }
@@ -0,0 +1,95 @@
+use soroban_sdk::{contracttype, Address};
+
+/// Contributions a member paid in advance for future cycles.
+///
+/// The escrowed funds are held by the contract and applied one cycle at a
+/// time as each cycle opens, producing the same contribution records as a
+/// regular payment. Whatever has not been applied can be refunded once the
+/// group has ended or the member has left it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Prepayment {
+    /// ID of the group the prepayment belongs to.
+    pub group_id: u64,
+
+    /// Member whose future contributions are covered.
+    pub member: Address,
+
+    /// Amount escrowed for each cycle, in stroops: the member's
+    /// contribution plus any insurance levy on it.
+    pub amount_per_cycle: i128,
+
+    /// Number of cycles still covered by the escrow.
+    pub cycles_remaining: u32,
+
+    /// Timestamp of the last prepayment or application.
+    pub updated_at: u64,
+}
+
+impl Prepayment {
+    /// Creates an empty prepayment for a member.
+    pub fn new(group_id: u64, member: Address, amount_per_cycle: i128, updated_at: u64) -> Self {
+        Self {
+            group_id,
+            member,
+            amount_per_cycle,
+            cycles_remaining: 0,
+            updated_at,
+        }
+    }
+
+    /// Amount still held in escrow, in stroops.
+    pub fn balance(&self) -> i128 {
+        self.amount_per_cycle * self.cycles_remaining as i128
+    }
+
+    /// Returns true if no cycles are left to apply.
+    pub fn is_exhausted(&self) -> bool {
+        self.cycles_remaining == 0
+    }
+
+    /// Uses up one prepaid cycle. Returns false if none were left.
+    pub fn consume_cycle(&mut self, timestamp: u64) -> bool {
+        if self.is_exhausted() {
+            return false;
+        }
+
+        self.cycles_remaining -= 1;
+        self.updated_at = timestamp;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_prepayment_balance() {
+        let env = Env::default();
+        let mut prepayment = Prepayment::new(1, Address::generate(&env), 100, 1000);
+        assert!(prepayment.is_exhausted());
+        assert_eq!(prepayment.balance(), 0);
+
+        prepayment.cycles_remaining = 3;
+        assert!(!prepayment.is_exhausted());
+        assert_eq!(prepayment.balance(), 300);
+    }
+
+    #[test]
+    fn test_consume_cycle() {
+        let env = Env::default();
+        let mut prepayment = Prepayment::new(1, Address::generate(&env), 100, 1000);
+        prepayment.cycles_remaining = 2;
+
+        assert!(prepayment.consume_cycle(2000));
+        assert_eq!(prepayment.cycles_remaining, 1);
+        assert_eq!(prepayment.updated_at, 2000);
+
+        assert!(prepayment.consume_cycle(3000));
+        assert!(prepayment.is_exhausted());
+        assert!(!prepayment.consume_cycle(4000));
+        assert_eq!(prepayment.updated_at, 3000);
+    }
+}
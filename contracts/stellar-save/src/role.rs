@@ -0,0 +1,80 @@
+use soroban_sdk::contracttype;
+
+/// Per-group roles controlling who may perform privileged operations.
+///
+/// Roles are ordered by privilege: `Member < Treasurer < Admin < Owner`.
+/// An operation that requires a role can be performed by anyone holding that
+/// role or a higher one.
+///
+/// - `Owner`: exactly one per group, always the address stored in
+///   `Group::creator`. Can do everything, including granting Admin and
+///   transferring ownership.
+/// - `Admin`: co-organizer. Manages membership, invites and group settings,
+///   and can grant or revoke the Treasurer role.
+/// - `Treasurer`: can execute payouts.
+/// - `Member`: no privileges beyond regular participation.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum GroupRole {
+    Member = 0,
+    Treasurer = 1,
+    Admin = 2,
+    Owner = 3,
+}
+
+impl GroupRole {
+    /// Returns true if this role grants at least the privileges of `required`.
+    pub fn satisfies(&self, required: GroupRole) -> bool {
+        *self >= required
+    }
+
+    /// Returns true if a holder of this role may change another address from
+    /// `current` to `new`. The caller must outrank both roles, so Admins can
+    /// manage Treasurers but not other Admins, and nobody can touch the Owner.
+    pub fn can_assign(&self, current: GroupRole, new: GroupRole) -> bool {
+        *self > current && *self > new
+    }
+
+    /// Returns true if the role can be granted through `grant_role`.
+    /// Ownership is only moved with `transfer_ownership`, and `Member` is the
+    /// implicit role left after `revoke_role`.
+    pub fn is_grantable(&self) -> bool {
+        matches!(self, GroupRole::Treasurer | GroupRole::Admin)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_ordering() {
+        assert!(GroupRole::Owner.satisfies(GroupRole::Admin));
+        assert!(GroupRole::Admin.satisfies(GroupRole::Admin));
+        assert!(GroupRole::Admin.satisfies(GroupRole::Treasurer));
+        assert!(!GroupRole::Treasurer.satisfies(GroupRole::Admin));
+        assert!(!GroupRole::Member.satisfies(GroupRole::Treasurer));
+    }
+
+    #[test]
+    fn test_can_assign() {
+        assert!(GroupRole::Owner.can_assign(GroupRole::Member, GroupRole::Admin));
+        assert!(GroupRole::Owner.can_assign(GroupRole::Admin, GroupRole::Member));
+        assert!(GroupRole::Admin.can_assign(GroupRole::Member, GroupRole::Treasurer));
+        assert!(GroupRole::Admin.can_assign(GroupRole::Treasurer, GroupRole::Member));
+
+        assert!(!GroupRole::Admin.can_assign(GroupRole::Member, GroupRole::Admin));
+        assert!(!GroupRole::Admin.can_assign(GroupRole::Admin, GroupRole::Member));
+        assert!(!GroupRole::Owner.can_assign(GroupRole::Owner, GroupRole::Admin));
+        assert!(!GroupRole::Treasurer.can_assign(GroupRole::Member, GroupRole::Treasurer));
+    }
+
+    #[test]
+    fn test_is_grantable() {
+        assert!(GroupRole::Admin.is_grantable());
+        assert!(GroupRole::Treasurer.is_grantable());
+        assert!(!GroupRole::Owner.is_grantable());
+        assert!(!GroupRole::Member.is_grantable());
+    }
+}
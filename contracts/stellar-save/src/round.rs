@@ -0,0 +1,65 @@
+use soroban_sdk::contracttype;
+
+/// Opts a group into running round after round instead of completing once
+/// every member has been paid.
+///
+/// When the last cycle of a round is paid out, a renewal window of `window`
+/// seconds opens. Members opt in or out during the window, and once it
+/// closes anyone can start the next round with the members who opted in.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RenewalPolicy {
+    /// Length of the renewal window in seconds.
+    pub window: u64,
+}
+
+impl RenewalPolicy {
+    /// Returns true if the window has a usable length.
+    pub fn is_valid(&self) -> bool {
+        self.window > 0
+    }
+
+    /// Returns when a window opened at `opened_at` closes, or `None` on
+    /// overflow.
+    pub fn closes_at(&self, opened_at: u64) -> Option<u64> {
+        opened_at.checked_add(self.window)
+    }
+}
+
+/// Summary of a finished round of a renewing group.
+///
+/// Contribution and payout records of the round stay queryable under the
+/// round number after the next round starts.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundSummary {
+    /// Round number, starting at 0.
+    pub round: u32,
+
+    /// Number of cycles the round ran.
+    pub cycles: u32,
+
+    /// Number of members in the round.
+    pub member_count: u32,
+
+    /// When the round's first cycle started.
+    pub started_at: u64,
+
+    /// When the round's last payout was made and the renewal window opened.
+    pub completed_at: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_renewal_policy_window() {
+        let policy = RenewalPolicy { window: 3600 };
+
+        assert!(policy.is_valid());
+        assert!(!RenewalPolicy { window: 0 }.is_valid());
+        assert_eq!(policy.closes_at(1_000), Some(4_600));
+        assert_eq!(policy.closes_at(u64::MAX), None);
+    }
+}
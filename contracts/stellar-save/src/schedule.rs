@@ -0,0 +1,253 @@
+use soroban_sdk::contracttype;
+
+/// Number of seconds in a day.
+pub const SECONDS_PER_DAY: u64 = 86400;
+
+/// Number of seconds in a week.
+pub const SECONDS_PER_WEEK: u64 = 7 * SECONDS_PER_DAY;
+
+/// Defines how cycle deadlines are laid out once a group has started.
+///
+/// `FixedSeconds` keeps the original behavior: cycle `n` ends at
+/// `started_at + cycle_duration * (n + 1)`. The calendar schedules instead
+/// align every deadline to midnight UTC on a given weekday or day of the
+/// month, so deadlines stay on payday instead of drifting.
+///
+/// For calendar schedules the first deadline is the first matching boundary
+/// strictly after `started_at`, so the first cycle may be shorter than the
+/// following ones. Pair them with a scheduled start to control this.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum CycleSchedule {
+    /// Cycles last `cycle_duration` seconds, anchored at `started_at`.
+    #[default]
+    FixedSeconds,
+
+    /// Cycles end every week at 00:00 UTC on the given weekday
+    /// (0 = Monday, ..., 6 = Sunday).
+    Weekly(u32),
+
+    /// Cycles end every month at 00:00 UTC on the given day of the month
+    /// (1-31). Months shorter than the requested day end on their last day.
+    Monthly(u32),
+}
+
+impl CycleSchedule {
+    /// Checks that the weekday or day-of-month parameter is in range.
+    pub fn is_valid(&self) -> bool {
+        match self {
+            CycleSchedule::FixedSeconds => true,
+            CycleSchedule::Weekly(weekday) => *weekday <= 6,
+            CycleSchedule::Monthly(day) => (1..=31).contains(day),
+        }
+    }
+
+    /// Calculates the deadline (end timestamp) of a cycle.
+    ///
+    /// # Arguments
+    /// * `started_at` - Timestamp the group's first cycle started
+    /// * `cycle_duration` - Cycle length in seconds, used by `FixedSeconds`
+    /// * `cycle` - Cycle number (0-indexed)
+    ///
+    /// # Returns
+    /// The deadline timestamp, or `None` on overflow.
+    pub fn cycle_deadline(&self, started_at: u64, cycle_duration: u64, cycle: u32) -> Option<u64> {
+        match self {
+            CycleSchedule::FixedSeconds => (cycle as u64)
+                .checked_add(1)?
+                .checked_mul(cycle_duration)
+                .and_then(|offset| started_at.checked_add(offset)),
+            CycleSchedule::Weekly(weekday) => {
+                let first = first_weekday_after(started_at, *weekday)?;
+                (cycle as u64)
+                    .checked_mul(SECONDS_PER_WEEK)
+                    .and_then(|offset| first.checked_add(offset))
+            }
+            CycleSchedule::Monthly(day) => monthly_deadline(started_at, *day, cycle),
+        }
+    }
+}
+
+/// Returns midnight UTC of the first `weekday` strictly after `timestamp`.
+fn first_weekday_after(timestamp: u64, weekday: u32) -> Option<u64> {
+    let day = timestamp / SECONDS_PER_DAY;
+    // 1970-01-01 was a Thursday (index 3 with Monday = 0)
+    let current_weekday = (day + 3) % 7;
+    let mut target_day = day + (weekday as u64 + 7 - current_weekday) % 7;
+    if target_day.checked_mul(SECONDS_PER_DAY)? <= timestamp {
+        target_day += 7;
+    }
+    target_day.checked_mul(SECONDS_PER_DAY)
+}
+
+/// Returns the deadline of `cycle` for a monthly schedule on `day`.
+fn monthly_deadline(started_at: u64, day: u32, cycle: u32) -> Option<u64> {
+    let (year, month, _) = civil_from_days((started_at / SECONDS_PER_DAY) as i64);
+    let mut month_index = year.checked_mul(12)? + (month as i64 - 1);
+
+    if month_boundary(month_index, day)? <= started_at {
+        month_index += 1;
+    }
+
+    month_boundary(month_index.checked_add(cycle as i64)?, day)
+}
+
+/// Returns midnight UTC of `day` (clamped to the month length) in the month
+/// identified by `month_index` (`year * 12 + month - 1`).
+fn month_boundary(month_index: i64, day: u32) -> Option<u64> {
+    let year = month_index.div_euclid(12);
+    let month = (month_index.rem_euclid(12) + 1) as u32;
+    let day = day.min(days_in_month(year, month));
+    let days = days_from_civil(year, month, day);
+    u64::try_from(days).ok()?.checked_mul(SECONDS_PER_DAY)
+}
+
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year =
+        (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+/// Proleptic Gregorian date (year, month, day) for days since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2024-01-01 00:00:00 UTC (a Monday)
+    const JAN_1_2024: u64 = 1704067200;
+
+    #[test]
+    fn test_civil_conversions() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(
+            days_from_civil(2024, 1, 1) as u64 * SECONDS_PER_DAY,
+            JAN_1_2024
+        );
+        assert_eq!(civil_from_days(days_from_civil(2024, 2, 29)), (2024, 2, 29));
+    }
+
+    #[test]
+    fn test_fixed_seconds_deadline() {
+        let schedule = CycleSchedule::FixedSeconds;
+
+        assert_eq!(schedule.cycle_deadline(1000, 3600, 0), Some(4600));
+        assert_eq!(schedule.cycle_deadline(1000, 3600, 2), Some(11800));
+        assert_eq!(schedule.cycle_deadline(u64::MAX, 3600, 0), None);
+    }
+
+    #[test]
+    fn test_weekly_deadline() {
+        // Start Monday 2024-01-01 at noon, deadlines on Fridays
+        let schedule = CycleSchedule::Weekly(4);
+        let started_at = JAN_1_2024 + 12 * 3600;
+
+        assert_eq!(
+            schedule.cycle_deadline(started_at, 0, 0),
+            Some(JAN_1_2024 + 4 * SECONDS_PER_DAY)
+        );
+        assert_eq!(
+            schedule.cycle_deadline(started_at, 0, 1),
+            Some(JAN_1_2024 + 11 * SECONDS_PER_DAY)
+        );
+    }
+
+    #[test]
+    fn test_weekly_deadline_starting_on_boundary() {
+        // Starting exactly at Monday midnight moves the first deadline a week out
+        let schedule = CycleSchedule::Weekly(0);
+
+        assert_eq!(
+            schedule.cycle_deadline(JAN_1_2024, 0, 0),
+            Some(JAN_1_2024 + SECONDS_PER_WEEK)
+        );
+    }
+
+    #[test]
+    fn test_monthly_deadline() {
+        // Start 2024-01-10, deadlines on the 25th
+        let schedule = CycleSchedule::Monthly(25);
+        let started_at = JAN_1_2024 + 9 * SECONDS_PER_DAY;
+
+        assert_eq!(
+            schedule.cycle_deadline(started_at, 0, 0),
+            Some(days_from_civil(2024, 1, 25) as u64 * SECONDS_PER_DAY)
+        );
+        assert_eq!(
+            schedule.cycle_deadline(started_at, 0, 1),
+            Some(days_from_civil(2024, 2, 25) as u64 * SECONDS_PER_DAY)
+        );
+        assert_eq!(
+            schedule.cycle_deadline(started_at, 0, 12),
+            Some(days_from_civil(2025, 1, 25) as u64 * SECONDS_PER_DAY)
+        );
+    }
+
+    #[test]
+    fn test_monthly_deadline_clamps_to_month_end() {
+        // Start 2024-01-31, deadlines on the 31st
+        let schedule = CycleSchedule::Monthly(31);
+        let started_at = days_from_civil(2024, 1, 31) as u64 * SECONDS_PER_DAY + 60;
+
+        assert_eq!(
+            schedule.cycle_deadline(started_at, 0, 0),
+            Some(days_from_civil(2024, 2, 29) as u64 * SECONDS_PER_DAY)
+        );
+        assert_eq!(
+            schedule.cycle_deadline(started_at, 0, 1),
+            Some(days_from_civil(2024, 3, 31) as u64 * SECONDS_PER_DAY)
+        );
+        assert_eq!(
+            schedule.cycle_deadline(started_at, 0, 2),
+            Some(days_from_civil(2024, 4, 30) as u64 * SECONDS_PER_DAY)
+        );
+    }
+
+    #[test]
+    fn test_schedule_validation() {
+        assert!(CycleSchedule::FixedSeconds.is_valid());
+        assert!(CycleSchedule::Weekly(6).is_valid());
+        assert!(!CycleSchedule::Weekly(7).is_valid());
+        assert!(CycleSchedule::Monthly(1).is_valid());
+        assert!(CycleSchedule::Monthly(31).is_valid());
+        assert!(!CycleSchedule::Monthly(0).is_valid());
+        assert!(!CycleSchedule::Monthly(32).is_valid());
+    }
+}
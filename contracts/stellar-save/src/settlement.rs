@@ -0,0 +1,87 @@
+use soroban_sdk::{contracttype, Address};
+
+/// Settlement of a member's position when they are removed from a group.
+///
+/// The net position is what the member paid in minus what they took out.
+/// A positive value is owed back to the member; a negative value is owed by
+/// the member to the group. The contract does not transfer it: what is left
+/// after the current cycle's refund was paid out to other members, so the
+/// members settle it between themselves. The record is kept after the
+/// member's profile is deleted so the settlement can be audited later.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberSettlement {
+    /// ID of the group the member left.
+    pub group_id: u64,
+
+    /// Address of the departing member.
+    pub member: Address,
+
+    /// Total contributions made by the member, in stroops.
+    pub total_contributed: i128,
+
+    /// Total payouts received by the member, in stroops.
+    pub total_received: i128,
+
+    /// `total_contributed - total_received`.
+    pub net_position: i128,
+
+    /// Timestamp the settlement was recorded.
+    pub settled_at: u64,
+}
+
+impl MemberSettlement {
+    /// Creates a settlement from the member's contribution and payout totals.
+    pub fn new(
+        group_id: u64,
+        member: Address,
+        total_contributed: i128,
+        total_received: i128,
+        settled_at: u64,
+    ) -> Self {
+        Self {
+            group_id,
+            member,
+            total_contributed,
+            total_received,
+            net_position: total_contributed - total_received,
+            settled_at,
+        }
+    }
+
+    /// Amount owed back to the member (0 if they owe the group).
+    pub fn refund_due(&self) -> i128 {
+        self.net_position.max(0)
+    }
+
+    /// Amount the member owes the group (0 if they are owed a refund).
+    pub fn amount_owed(&self) -> i128 {
+        (-self.net_position).max(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, Env};
+
+    #[test]
+    fn test_settlement_refund() {
+        let env = Env::default();
+        let settlement = MemberSettlement::new(1, Address::generate(&env), 300, 0, 1000);
+
+        assert_eq!(settlement.net_position, 300);
+        assert_eq!(settlement.refund_due(), 300);
+        assert_eq!(settlement.amount_owed(), 0);
+    }
+
+    #[test]
+    fn test_settlement_amount_owed() {
+        let env = Env::default();
+        let settlement = MemberSettlement::new(1, Address::generate(&env), 200, 500, 1000);
+
+        assert_eq!(settlement.net_position, -300);
+        assert_eq!(settlement.refund_due(), 0);
+        assert_eq!(settlement.amount_owed(), 300);
+    }
+}
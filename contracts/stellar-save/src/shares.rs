@@ -0,0 +1,160 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+/// Share units per whole share. Shares are counted in halves, so a member
+/// holding 0.5 shares has 1 unit, 1 share is 2 units and 2 shares is 4 units.
+pub const UNITS_PER_SHARE: u32 = 2;
+
+/// Share units a member holds unless an admin sets otherwise (1 share).
+pub const DEFAULT_SHARE_UNITS: u32 = UNITS_PER_SHARE;
+
+/// Maximum share units a single member can hold (4 shares).
+pub const MAX_SHARE_UNITS: u32 = 4 * UNITS_PER_SHARE;
+
+/// A member's claim on one cycle's pool.
+///
+/// Every cycle pays out one full pool, i.e. one whole share's worth of slots.
+/// A single-share member fills a cycle on their own, a double-share member
+/// fills two cycles, and two half-share members split one cycle.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutSlot {
+    /// Cycle the slot is paid out in.
+    pub cycle: u32,
+
+    /// Member receiving the slot.
+    pub recipient: Address,
+
+    /// Share units of the pool the member receives in this cycle.
+    pub share_units: u32,
+}
+
+/// Returns true if `share_units` is between half a share and `MAX_SHARE_UNITS`.
+pub fn is_valid_share_units(share_units: u32) -> bool {
+    (1..=MAX_SHARE_UNITS).contains(&share_units)
+}
+
+/// Returns the amount a member holding `share_units` contributes each cycle,
+/// or `None` on overflow.
+pub fn contribution_for_units(contribution_amount: i128, share_units: u32) -> Option<i128> {
+    contribution_amount
+        .checked_mul(share_units as i128)
+        .map(|amount| amount / UNITS_PER_SHARE as i128)
+}
+
+/// Returns the part of a cycle's `pool` paid to a slot of `share_units`.
+pub fn slot_amount(pool: i128, share_units: u32) -> i128 {
+    pool * share_units as i128 / UNITS_PER_SHARE as i128
+}
+
+/// Lays out the payout slots for members given in payout order, each with
+/// their share units.
+///
+/// Slots fill cycles in order, one whole share per cycle. A member whose
+/// units do not line up with a cycle boundary is split across two cycles.
+pub fn build_payout_slots(env: &Env, members: &Vec<(Address, u32)>) -> Vec<PayoutSlot> {
+    let mut slots = Vec::new(env);
+    let mut cycle: u32 = 0;
+    let mut filled: u32 = 0;
+
+    for (recipient, share_units) in members.iter() {
+        let mut remaining = share_units;
+        while remaining > 0 {
+            let taken = remaining.min(UNITS_PER_SHARE - filled);
+            slots.push_back(PayoutSlot {
+                cycle,
+                recipient: recipient.clone(),
+                share_units: taken,
+            });
+
+            remaining -= taken;
+            filled += taken;
+            if filled == UNITS_PER_SHARE {
+                cycle += 1;
+                filled = 0;
+            }
+        }
+    }
+
+    slots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_share_units_validation() {
+        assert!(!is_valid_share_units(0));
+        assert!(is_valid_share_units(1));
+        assert!(is_valid_share_units(DEFAULT_SHARE_UNITS));
+        assert!(is_valid_share_units(MAX_SHARE_UNITS));
+        assert!(!is_valid_share_units(MAX_SHARE_UNITS + 1));
+    }
+
+    #[test]
+    fn test_contribution_for_units() {
+        assert_eq!(contribution_for_units(100, 1), Some(50));
+        assert_eq!(contribution_for_units(100, 2), Some(100));
+        assert_eq!(contribution_for_units(100, 4), Some(200));
+        assert_eq!(contribution_for_units(i128::MAX, 2), None);
+        assert_eq!(slot_amount(400, 1), 200);
+    }
+
+    #[test]
+    fn test_build_payout_slots() {
+        let env = Env::default();
+        let single = Address::generate(&env);
+        let double = Address::generate(&env);
+        let half_a = Address::generate(&env);
+        let half_b = Address::generate(&env);
+
+        let members = Vec::from_array(
+            &env,
+            [
+                (single.clone(), 2),
+                (double.clone(), 4),
+                (half_a.clone(), 1),
+                (half_b.clone(), 1),
+            ],
+        );
+        let slots = build_payout_slots(&env, &members);
+
+        let expected = [
+            (0, single, 2),
+            (1, double.clone(), 2),
+            (2, double, 2),
+            (3, half_a, 1),
+            (3, half_b, 1),
+        ];
+        assert_eq!(slots.len(), expected.len() as u32);
+        for (i, (cycle, recipient, share_units)) in expected.into_iter().enumerate() {
+            assert_eq!(
+                slots.get(i as u32).unwrap(),
+                PayoutSlot {
+                    cycle,
+                    recipient,
+                    share_units
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_build_payout_slots_splits_across_cycles() {
+        let env = Env::default();
+        let half = Address::generate(&env);
+        let single = Address::generate(&env);
+        let other_half = Address::generate(&env);
+
+        let members = Vec::from_array(&env, [(half, 1), (single.clone(), 2), (other_half, 1)]);
+        let slots = build_payout_slots(&env, &members);
+
+        // The single-share member receives half a pool in cycles 0 and 1
+        assert_eq!(slots.len(), 4);
+        assert_eq!(slots.get(1).unwrap().recipient, single);
+        assert_eq!(slots.get(1).unwrap().cycle, 0);
+        assert_eq!(slots.get(2).unwrap().recipient, single);
+        assert_eq!(slots.get(2).unwrap().cycle, 1);
+    }
+}
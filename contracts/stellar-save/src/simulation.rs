@@ -0,0 +1,464 @@
+//! Deterministic simulation of whole group lifecycles (tests only).
+//!
+//! A [`Simulation`] registers the contract next to a test Stellar Asset
+//! Contract, generates one member per scripted [`Behavior`] and drives a
+//! group from creation to completion, moving the ledger clock forward as the
+//! script requires. After every step it checks the invariants that must hold
+//! however members behave:
+//!
+//! - the escrow recorded by the contract (contributions minus payouts) never
+//!   goes negative and matches what was actually paid in and out,
+//! - the contract's token balance matches the contributions pulled from
+//!   members less the payouts and refunds sent back out, and is exactly the
+//!   recorded escrow,
+//! - nobody is paid twice, and at the end every remaining member has been
+//!   paid exactly once,
+//! - the stored status only moves along legal transitions, and `Group.status`
+//!   always agrees with it.
+//!
+//! Runs are deterministic: addresses come from the test environment's
+//! generator and time only moves when the script moves it.
+
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{token, Address, Env, Map, Vec};
+
+use crate::{
+    Group, GroupStatus, PayoutRecord, ProposalAction, ProposalStatus, StellarSaveContract,
+    StellarSaveContractClient, StorageKeyBuilder,
+};
+
+const CONTRIBUTION: i128 = 100;
+const CYCLE_DURATION: u64 = 3600;
+const START: u64 = 1_000;
+
+/// How a simulated member pays.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Behavior {
+    /// Enrolled in auto-debit with enough funds, so every contribution is
+    /// pulled as soon as the cycle opens.
+    OnTime,
+
+    /// Pays manually once the cycle's deadline has passed.
+    Late,
+
+    /// Pays manually on time until receiving the payout, then stops paying
+    /// and leaves the creator to cover the missed contributions.
+    DefaultsAfterPayout,
+
+    /// Enrolled in auto-debit until the admin removes them at the start of
+    /// `cycle`, before their payout.
+    Leaves { cycle: u32 },
+}
+
+impl Behavior {
+    fn auto_debit(&self) -> bool {
+        matches!(self, Behavior::OnTime | Behavior::Leaves { .. })
+    }
+}
+
+/// A scripted group lifecycle and the running totals it is checked against.
+pub struct Simulation<'a> {
+    env: Env,
+    contract_id: Address,
+    token: Address,
+    creator: Address,
+    group_id: u64,
+    members: Vec<Address>,
+    behaviors: &'a [Behavior],
+    left: Vec<Address>,
+    paid: Vec<Address>,
+    pause_at: Option<u32>,
+
+    /// Status last seen in storage.
+    status: GroupStatus,
+    /// Contributions paid in, less those refunded to members who left.
+    contributed: i128,
+    /// Gross payouts made.
+    paid_out: i128,
+    /// Tokens pulled from members, by auto-debit or manual payment.
+    pulled: i128,
+    /// Tokens refunded to members who left.
+    refunded: i128,
+    /// Members whose contribution for the current cycle is paid.
+    paid_this_cycle: Vec<Address>,
+}
+
+impl<'a> Simulation<'a> {
+    /// Creates a Pending group with one member per behavior.
+    pub fn new(behaviors: &'a [Behavior]) -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+        env.ledger().set_timestamp(START);
+
+        let contract_id = env.register(StellarSaveContract, ());
+        let token_admin = Address::generate(&env);
+        let token = env
+            .register_stellar_asset_contract_v2(token_admin)
+            .address();
+        let creator = Address::generate(&env);
+
+        let mut members = Vec::new(&env);
+        for _ in behaviors {
+            members.push_back(Address::generate(&env));
+        }
+
+        let client = StellarSaveContractClient::new(&env, &contract_id);
+        let group_id = client.create_group(
+            &creator,
+            &CONTRIBUTION,
+            &CYCLE_DURATION,
+            &(behaviors.len() as u32),
+            &2,
+            &None,
+        );
+        client.set_group_token(&group_id, &creator, &token);
+
+        let mut simulation = Self {
+            left: Vec::new(&env),
+            paid: Vec::new(&env),
+            paid_this_cycle: Vec::new(&env),
+            env,
+            contract_id,
+            token,
+            creator,
+            group_id,
+            members,
+            behaviors,
+            pause_at: None,
+            status: GroupStatus::Pending,
+            contributed: 0,
+            paid_out: 0,
+            pulled: 0,
+            refunded: 0,
+        };
+        simulation.check();
+        simulation
+    }
+
+    /// Pauses and resumes the group by governance vote during `cycle`.
+    pub fn with_pause(mut self, cycle: u32) -> Self {
+        self.pause_at = Some(cycle);
+        self
+    }
+
+    /// Runs the group through every cycle until it completes.
+    pub fn run(&mut self) {
+        // 1. Everyone joins, funding and approving every cycle up front
+        let cycles = self.behaviors.len() as i128;
+        let token_admin = token::StellarAssetClient::new(&self.env, &self.token);
+        let token_client = token::TokenClient::new(&self.env, &self.token);
+        let cover = CONTRIBUTION * cycles * cycles;
+        token_admin.mint(&self.creator, &cover);
+        token_client.approve(&self.creator, &self.contract_id, &cover, &1_000);
+        for (member, behavior) in self.members.iter().zip(self.behaviors) {
+            self.client().join_group(&self.group_id, &member);
+            token_admin.mint(&member, &(CONTRIBUTION * cycles));
+            token_client.approve(&member, &self.contract_id, &(CONTRIBUTION * cycles), &1_000);
+            if behavior.auto_debit() {
+                self.client().enroll_auto_debit(&self.group_id, &member);
+            }
+            self.check();
+        }
+
+        // 2. Activating opens cycle 0
+        self.client().activate_group(&self.group_id, &self.creator);
+        self.open_cycle();
+        self.check();
+
+        while self.status == GroupStatus::Active {
+            self.run_cycle();
+        }
+
+        // 3. Every member still in the group was paid exactly once
+        assert_eq!(self.status, GroupStatus::Completed);
+        for (member, behavior) in self.members.iter().zip(self.behaviors) {
+            let expected = if self.left.contains(&member) { 0 } else { 1 };
+            assert_eq!(
+                self.payouts_to(&member),
+                expected,
+                "{:?} member paid out the wrong number of times",
+                behavior
+            );
+        }
+    }
+
+    fn run_cycle(&mut self) {
+        let group = self.client().get_group(&self.group_id);
+        let cycle = group.current_cycle;
+        let opens_at = group.started_at + cycle as u64 * CYCLE_DURATION;
+        if self.env.ledger().timestamp() < opens_at {
+            self.env.ledger().set_timestamp(opens_at);
+        }
+
+        // 1. Members scheduled to leave go before paying anything more
+        for (member, behavior) in self.members.iter().zip(self.behaviors) {
+            if *behavior == (Behavior::Leaves { cycle }) {
+                self.client()
+                    .remove_member(&self.group_id, &self.creator, &member);
+                if self.paid_this_cycle.contains(&member) {
+                    self.contributed -= CONTRIBUTION;
+                    self.refunded += CONTRIBUTION;
+                }
+                self.left.push_back(member);
+                self.check();
+            }
+        }
+
+        // 2. Manual payers who are on time
+        for (member, behavior) in self.members.iter().zip(self.behaviors) {
+            if *behavior == Behavior::DefaultsAfterPayout && !self.paid.contains(&member) {
+                self.contribute(&member);
+            }
+        }
+
+        if self.pause_at == Some(cycle) {
+            self.vote(ProposalAction::Pause);
+            assert_eq!(self.status, GroupStatus::Paused);
+            self.vote(ProposalAction::Resume);
+            assert_eq!(self.status, GroupStatus::Active);
+        }
+
+        // 3. Late payers once the deadline has passed
+        let deadline = self
+            .client()
+            .get_contribution_deadline(&self.group_id, &cycle);
+        self.env.ledger().set_timestamp(deadline + 1);
+        for (member, behavior) in self.members.iter().zip(self.behaviors) {
+            if *behavior == Behavior::Late {
+                self.contribute(&member);
+            }
+        }
+
+        // 4. The creator covers defaulters so the pool can be paid in full
+        for (member, behavior) in self.members.iter().zip(self.behaviors) {
+            if *behavior == Behavior::DefaultsAfterPayout && self.paid.contains(&member) {
+                let creator = self.creator.clone();
+                self.contribute_from(&creator, &member);
+            }
+        }
+
+        // 5. The treasurer pays the cycle out and moves the group on
+        let pool = self.client().get_pool_info(&self.group_id);
+        let recipient = self
+            .client()
+            .get_payout_slots(&self.group_id)
+            .iter()
+            .find(|slot| slot.cycle == cycle)
+            .map(|slot| slot.recipient)
+            .unwrap();
+        self.client().transfer_payout(
+            &self.group_id,
+            &self.creator,
+            &recipient,
+            &pool.total_pool_amount,
+            &cycle,
+        );
+        self.paid_out += pool.total_pool_amount;
+        self.paid.push_back(recipient);
+        self.check();
+
+        self.client().advance_cycle(&self.group_id, &self.creator);
+        self.open_cycle();
+        self.check();
+    }
+
+    /// Records the auto-debit pulls of a cycle that just opened.
+    fn open_cycle(&mut self) {
+        self.paid_this_cycle = Vec::new(&self.env);
+        if self.client().get_group(&self.group_id).status != GroupStatus::Active {
+            return;
+        }
+        for (member, behavior) in self.members.iter().zip(self.behaviors) {
+            if behavior.auto_debit() && !self.left.contains(&member) {
+                self.contributed += CONTRIBUTION;
+                self.pulled += CONTRIBUTION;
+                self.paid_this_cycle.push_back(member);
+            }
+        }
+    }
+
+    fn contribute(&mut self, member: &Address) {
+        self.contribute_from(member, member);
+    }
+
+    fn contribute_from(&mut self, payer: &Address, member: &Address) {
+        self.client().contribute_for(&self.group_id, payer, member);
+        self.contributed += CONTRIBUTION;
+        self.pulled += CONTRIBUTION;
+        self.paid_this_cycle.push_back(member.clone());
+        self.check();
+    }
+
+    /// Passes a proposal with every remaining member voting in favor.
+    fn vote(&mut self, action: ProposalAction) {
+        let mut voters = Vec::new(&self.env);
+        for member in self.members.iter() {
+            if !self.left.contains(&member) {
+                voters.push_back(member);
+            }
+        }
+        let proposer = voters.get(0).unwrap();
+        let proposal_id = self
+            .client()
+            .create_proposal(&self.group_id, &proposer, &action);
+        for voter in voters.iter() {
+            let status = self
+                .client()
+                .vote(&self.group_id, &proposal_id, &voter, &true);
+            if status != ProposalStatus::Open {
+                assert_eq!(status, ProposalStatus::Executed);
+                break;
+            }
+        }
+        self.check();
+    }
+
+    fn client(&self) -> StellarSaveContractClient<'_> {
+        StellarSaveContractClient::new(&self.env, &self.contract_id)
+    }
+
+    /// Number of cycles paid out to `member`.
+    fn payouts_to(&self, member: &Address) -> u32 {
+        self.payout_counts().get(member.clone()).unwrap_or(0)
+    }
+
+    fn payout_counts(&self) -> Map<Address, u32> {
+        let group = self.client().get_group(&self.group_id);
+        let mut counts = Map::new(&self.env);
+        for record in self.payout_records(&group).iter() {
+            let count = counts.get(record.recipient.clone()).unwrap_or(0);
+            counts.set(record.recipient, count + 1);
+        }
+        counts
+    }
+
+    fn payout_records(&self, group: &Group) -> Vec<PayoutRecord> {
+        self.env.as_contract(&self.contract_id, || {
+            let mut records = Vec::new(&self.env);
+            for cycle in 0..=group.current_cycle {
+                let key = StorageKeyBuilder::payout_record(self.group_id, cycle);
+                if let Some(record) = self.env.storage().persistent().get(&key) {
+                    records.push_back(record);
+                }
+            }
+            records
+        })
+    }
+
+    /// Contributions minus payouts, as recorded by the contract.
+    fn escrow(&self, group: &Group) -> i128 {
+        let contributions: i128 = self.env.as_contract(&self.contract_id, || {
+            (0..=group.current_cycle)
+                .map(|cycle| {
+                    let key = StorageKeyBuilder::contribution_cycle_total(self.group_id, cycle);
+                    self.env.storage().persistent().get(&key).unwrap_or(0)
+                })
+                .sum()
+        });
+        let payouts: i128 = self
+            .payout_records(group)
+            .iter()
+            .map(|record| record.amount)
+            .sum();
+        contributions - payouts
+    }
+
+    fn stored_status(&self) -> GroupStatus {
+        self.env.as_contract(&self.contract_id, || {
+            self.env
+                .storage()
+                .persistent()
+                .get(&StorageKeyBuilder::group_status(self.group_id))
+                .unwrap()
+        })
+    }
+
+    /// Checks every invariant against the contract's current state.
+    fn check(&mut self) {
+        let group = self.client().get_group(&self.group_id);
+
+        // Status moves legally, and both copies of it agree
+        let status = self.stored_status();
+        assert!(
+            self.status.can_transition_to(&status),
+            "illegal status transition {} -> {}",
+            self.status,
+            status
+        );
+        assert_eq!(
+            group.status, status,
+            "Group.status disagrees with stored status"
+        );
+        self.status = status;
+
+        // The escrow is never overdrawn, and the tokens to back it are held
+        let escrow = self.escrow(&group);
+        assert!(escrow >= 0, "escrow went negative: {}", escrow);
+        assert_eq!(
+            escrow,
+            self.contributed - self.paid_out,
+            "escrow does not match contributions minus payouts"
+        );
+
+        let token = token::TokenClient::new(&self.env, &self.token);
+        let balance = token.balance(&self.contract_id);
+        assert_eq!(
+            balance,
+            self.pulled - self.paid_out - self.refunded,
+            "token balance does not match pulls minus payouts and refunds"
+        );
+        assert_eq!(balance, escrow, "token balance does not match the escrow");
+
+        for (member, count) in self.payout_counts().iter() {
+            assert_eq!(count, 1, "member paid out more than once");
+            assert!(
+                !self.left.contains(&member),
+                "member paid out after leaving"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_members_always_on_time() {
+        Simulation::new(&[Behavior::OnTime; 5]).run();
+    }
+
+    #[test]
+    fn test_late_and_defaulting_members() {
+        Simulation::new(&[
+            Behavior::OnTime,
+            Behavior::Late,
+            Behavior::DefaultsAfterPayout,
+            Behavior::Late,
+            Behavior::DefaultsAfterPayout,
+        ])
+        .run();
+    }
+
+    #[test]
+    fn test_member_leaves_mid_round() {
+        Simulation::new(&[
+            Behavior::OnTime,
+            Behavior::Late,
+            Behavior::OnTime,
+            Behavior::Leaves { cycle: 1 },
+        ])
+        .run();
+    }
+
+    #[test]
+    fn test_paused_and_resumed_by_vote() {
+        Simulation::new(&[
+            Behavior::OnTime,
+            Behavior::DefaultsAfterPayout,
+            Behavior::Late,
+        ])
+        .with_pause(1)
+        .run();
+    }
+}
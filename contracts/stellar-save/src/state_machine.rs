@@ -0,0 +1,518 @@
+//! Random call sequences against the contract, for property and fuzz tests.
+//!
+//! An [`Op`] is one contract call (or a jump of the ledger clock) with its
+//! targets given as small indices, so any generated value is a meaningful
+//! call. A [`Harness`] applies ops to a fresh contract and checks after each
+//! one that:
+//!
+//! - no call panicked or overflowed; contract errors are expected,
+//! - nobody has been paid out twice in a group,
+//! - `member_count` matches the stored member list, and members who made an
+//!   emergency withdrawal are no longer in it,
+//! - the stored status only moved along `GroupStatus::can_transition_to`,
+//!   and `Group.status` agrees with it,
+//! - the contract's token balance is exactly the contributions its groups
+//!   hold less what they paid out.
+//!
+//! The module is built for this crate's tests and, with the `testutils`
+//! feature, for the fuzz targets under `fuzz/`.
+
+// The `Arbitrary` derive expands to paths into `std`
+extern crate std;
+
+use soroban_sdk::testutils::{Address as _, EnvTestConfig, Ledger};
+use soroban_sdk::{token, Address, Env, Map, TryFromVal, Val, Vec};
+
+use crate::{
+    AssignmentMode, Group, GroupStatus, PayoutRecord, ProposalAction, ProposalStatus,
+    StellarSaveContract, StellarSaveContractClient, StorageKey, StorageKeyBuilder,
+};
+
+/// Number of generated accounts ops pick from.
+pub const ACCOUNTS: u32 = 6;
+
+/// Tokens each generated account holds and approves the contract to pull.
+const FUNDS: i128 = i128::MAX / ACCOUNTS as i128;
+
+/// One step of a generated call sequence.
+///
+/// `group` indexes the groups created so far and `creator` and `member` index
+/// the generated accounts; both wrap around.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "testutils", derive(arbitrary::Arbitrary))]
+pub enum Op {
+    Create {
+        creator: u8,
+        contribution_amount: i128,
+        cycle_duration: u64,
+        max_members: u32,
+        min_members: u32,
+    },
+    Join {
+        group: u8,
+        member: u8,
+    },
+    /// The group creator removes a member.
+    Leave {
+        group: u8,
+        member: u8,
+    },
+    AssignPositions {
+        group: u8,
+        random: bool,
+    },
+    Activate {
+        group: u8,
+    },
+    Contribute {
+        group: u8,
+        member: u8,
+    },
+    /// The creator pays out the current cycle and advances the group.
+    Execute {
+        group: u8,
+    },
+    /// Members vote the group paused, or resumed if it already is.
+    Pause {
+        group: u8,
+    },
+    /// Members vote the group cancelled.
+    Cancel {
+        group: u8,
+    },
+    EmergencyWithdraw {
+        group: u8,
+        member: u8,
+    },
+    /// Moves the ledger clock forward.
+    Wait {
+        seconds: u64,
+    },
+}
+
+/// A contract under random calls, and the statuses it was last seen in.
+pub struct Harness {
+    env: Env,
+    contract_id: Address,
+    token: Address,
+    accounts: Vec<Address>,
+    groups: Vec<u64>,
+    statuses: Map<u64, GroupStatus>,
+    withdrawn: Map<u64, Vec<Address>>,
+}
+
+impl Harness {
+    /// Registers the contract and a token in a fresh environment with mocked
+    /// auth, funding every account and approving the contract to pull.
+    pub fn new() -> Self {
+        let env = Env::new_with_config(EnvTestConfig {
+            capture_snapshot_at_drop: false,
+        });
+        env.mock_all_auths();
+        let contract_id = env.register(StellarSaveContract, ());
+        let token = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+
+        let token_admin = token::StellarAssetClient::new(&env, &token);
+        let token_client = token::TokenClient::new(&env, &token);
+        let mut accounts = Vec::new(&env);
+        for _ in 0..ACCOUNTS {
+            let account = Address::generate(&env);
+            token_admin.mint(&account, &FUNDS);
+            token_client.approve(&account, &contract_id, &FUNDS, &1_000);
+            accounts.push_back(account);
+        }
+
+        Self {
+            groups: Vec::new(&env),
+            statuses: Map::new(&env),
+            withdrawn: Map::new(&env),
+            env,
+            contract_id,
+            token,
+            accounts,
+        }
+    }
+
+    /// Applies `op`, then checks every invariant.
+    ///
+    /// # Panics
+    /// Panics if the contract aborts instead of returning an error, or if an
+    /// invariant no longer holds.
+    pub fn apply(&mut self, op: &Op) {
+        let client = StellarSaveContractClient::new(&self.env, &self.contract_id);
+
+        // Contract errors are expected; anything else is a host-level abort
+        macro_rules! call {
+            ($result:expr) => {
+                match $result {
+                    Ok(Ok(value)) => Some(value),
+                    Ok(Err(err)) => panic!("{:?}: result did not convert: {:?}", op, err),
+                    Err(Ok(_)) => None,
+                    Err(Err(err)) => panic!("{:?}: contract aborted: {:?}", op, err),
+                }
+            };
+        }
+
+        match *op {
+            Op::Create {
+                creator,
+                contribution_amount,
+                cycle_duration,
+                max_members,
+                min_members,
+            } => {
+                let creator = self.account(creator);
+                let group_id = call!(client.try_create_group(
+                    &creator,
+                    &contribution_amount,
+                    &cycle_duration,
+                    &max_members,
+                    &min_members,
+                    &None,
+                ));
+                if let Some(group_id) = group_id {
+                    call!(client.try_set_group_token(&group_id, &creator, &self.token));
+                    self.groups.push_back(group_id);
+                }
+            }
+            Op::Join { group, member } => {
+                if let Some(group_id) = self.group(group) {
+                    let member = self.account(member);
+                    if call!(client.try_join_group(&group_id, &member)).is_some() {
+                        self.forget_withdrawal(group_id, &member);
+                    }
+                }
+            }
+            Op::Leave { group, member } => {
+                if let Some(group) = self.load(group) {
+                    call!(client.try_remove_member(
+                        &group.id,
+                        &group.creator,
+                        &self.account(member),
+                    ));
+                }
+            }
+            Op::AssignPositions { group, random } => {
+                if let Some(group) = self.load(group) {
+                    let mode = if random {
+                        AssignmentMode::Random
+                    } else {
+                        AssignmentMode::Sequential
+                    };
+                    call!(client.try_assign_payout_positions(&group.id, &group.creator, &mode));
+                }
+            }
+            Op::Activate { group } => {
+                if let Some(group) = self.load(group) {
+                    call!(client.try_activate_group(&group.id, &group.creator));
+                }
+            }
+            Op::Contribute { group, member } => {
+                if let Some(group_id) = self.group(group) {
+                    let member = self.account(member);
+                    call!(client.try_contribute_for(&group_id, &member, &member));
+                }
+            }
+            Op::Execute { group } => {
+                if let Some(group) = self.load(group) {
+                    let pool = call!(client.try_get_pool_info(&group.id));
+                    let slots = call!(client.try_get_payout_slots(&group.id));
+                    if let (Some(pool), Some(slots)) = (pool, slots) {
+                        let slot = slots.iter().find(|slot| slot.cycle == pool.cycle);
+                        if let Some(slot) = slot {
+                            call!(client.try_transfer_payout(
+                                &group.id,
+                                &group.creator,
+                                &slot.recipient,
+                                &pool.total_pool_amount,
+                                &pool.cycle,
+                            ));
+                        }
+                    }
+                    call!(client.try_advance_cycle(&group.id, &group.creator));
+                }
+            }
+            Op::Pause { group } => {
+                if let Some(group) = self.load(group) {
+                    let action = if self.stored_status(group.id) == GroupStatus::Paused {
+                        ProposalAction::Resume
+                    } else {
+                        ProposalAction::Pause
+                    };
+                    self.pass(&client, &group, &action, op);
+                }
+            }
+            Op::Cancel { group } => {
+                if let Some(group) = self.load(group) {
+                    self.pass(&client, &group, &ProposalAction::Cancel, op);
+                }
+            }
+            Op::EmergencyWithdraw { group, member } => {
+                if let Some(group_id) = self.group(group) {
+                    let member = self.account(member);
+                    if call!(client.try_emergency_withdraw(&group_id, &member)).is_some() {
+                        let mut withdrawn =
+                            self.withdrawn.get(group_id).unwrap_or(Vec::new(&self.env));
+                        withdrawn.push_back(member);
+                        self.withdrawn.set(group_id, withdrawn);
+                    }
+                }
+            }
+            Op::Wait { seconds } => {
+                let now = self.env.ledger().timestamp();
+                self.env.ledger().set_timestamp(now.saturating_add(seconds));
+            }
+        }
+
+        self.check();
+    }
+
+    /// Checks the invariants of every group created so far.
+    pub fn check(&mut self) {
+        let mut escrow: i128 = 0;
+        for group_id in self.groups.iter() {
+            let group = self.stored_group(group_id);
+
+            let members = self.members(group_id);
+            assert_eq!(
+                group.member_count,
+                members.len(),
+                "group {}: member_count does not match its member list",
+                group_id
+            );
+            for member in self.withdrawn.get(group_id).unwrap_or(Vec::new(&self.env)).iter() {
+                assert!(
+                    !members.contains(&member),
+                    "group {}: member who withdrew is still listed",
+                    group_id
+                );
+            }
+
+            let mut payouts = Map::<Address, u32>::new(&self.env);
+            for cycle in 0..=group.current_cycle {
+                let total_key = StorageKeyBuilder::contribution_cycle_total(group_id, cycle);
+                escrow += self.stored::<i128>(&total_key).unwrap_or(0);
+
+                let key = StorageKeyBuilder::payout_record(group_id, cycle);
+                if let Some(record) = self.stored::<PayoutRecord>(&key) {
+                    let count = payouts.get(record.recipient.clone()).unwrap_or(0) + 1;
+                    assert_eq!(count, 1, "group {}: member paid out twice", group_id);
+                    payouts.set(record.recipient, count);
+                    escrow -= record.amount;
+                }
+            }
+
+            let status = self.stored_status(group_id);
+            let previous = self.statuses.get(group_id).unwrap_or(GroupStatus::Pending);
+            assert!(
+                previous.can_transition_to(&status),
+                "group {}: illegal status transition {} -> {}",
+                group_id,
+                previous,
+                status
+            );
+            assert_eq!(
+                group.status, status,
+                "group {}: Group.status disagrees with stored status",
+                group_id
+            );
+            self.statuses.set(group_id, status);
+        }
+
+        let token = token::TokenClient::new(&self.env, &self.token);
+        assert_eq!(
+            token.balance(&self.contract_id),
+            escrow,
+            "contract balance does not match the escrow of its groups"
+        );
+    }
+
+    /// Drops `member` from the group's withdrawn members once they rejoin.
+    fn forget_withdrawal(&mut self, group_id: u64, member: &Address) {
+        if let Some(withdrawn) = self.withdrawn.get(group_id) {
+            let mut remaining = Vec::new(&self.env);
+            for other in withdrawn.iter() {
+                if other != *member {
+                    remaining.push_back(other);
+                }
+            }
+            self.withdrawn.set(group_id, remaining);
+        }
+    }
+
+    /// Passes a governance proposal with every member voting in favor. The
+    /// proposal may be refused up front if it doesn't apply to the group.
+    fn pass(
+        &self,
+        client: &StellarSaveContractClient,
+        group: &Group,
+        action: &ProposalAction,
+        op: &Op,
+    ) {
+        let members = self.members(group.id);
+        let Some(proposer) = members.first() else {
+            return;
+        };
+        let proposal_id = match client.try_create_proposal(&group.id, &proposer, action) {
+            Ok(Ok(proposal_id)) => proposal_id,
+            Err(Ok(_)) => return,
+            other => panic!("{:?}: contract aborted: {:?}", op, other),
+        };
+        for voter in members.iter() {
+            match client.try_vote(&group.id, &proposal_id, &voter, &true) {
+                Ok(Ok(ProposalStatus::Open)) | Err(Ok(_)) => {}
+                Ok(Ok(_)) => break,
+                other => panic!("{:?}: contract aborted: {:?}", op, other),
+            }
+        }
+    }
+
+    fn account(&self, index: u8) -> Address {
+        self.accounts.get(index as u32 % ACCOUNTS).unwrap()
+    }
+
+    fn group(&self, index: u8) -> Option<u64> {
+        if self.groups.is_empty() {
+            return None;
+        }
+        self.groups.get(index as u32 % self.groups.len())
+    }
+
+    fn load(&self, index: u8) -> Option<Group> {
+        self.group(index)
+            .map(|group_id| self.stored_group(group_id))
+    }
+
+    fn stored_group(&self, group_id: u64) -> Group {
+        self.stored(&StorageKeyBuilder::group_data(group_id))
+            .unwrap()
+    }
+
+    fn members(&self, group_id: u64) -> Vec<Address> {
+        self.stored(&StorageKeyBuilder::group_members(group_id))
+            .unwrap_or(Vec::new(&self.env))
+    }
+
+    fn stored_status(&self, group_id: u64) -> GroupStatus {
+        self.stored(&StorageKeyBuilder::group_status(group_id))
+            .unwrap()
+    }
+
+    fn stored<T: TryFromVal<Env, Val>>(&self, key: &StorageKey) -> Option<T> {
+        self.env.as_contract(&self.contract_id, || {
+            self.env.storage().persistent().get(key)
+        })
+    }
+}
+
+impl Default for Harness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::vec;
+
+    fn op() -> impl Strategy<Value = Op> {
+        let index = any::<u8>;
+        prop_oneof![
+            1 => (
+                index(),
+                prop_oneof![1..1_000_000i128, any::<i128>()],
+                prop_oneof![1..7_200u64, any::<u64>()],
+                0..8u32,
+                0..8u32,
+            )
+                .prop_map(
+                    |(creator, contribution_amount, cycle_duration, max_members, min_members)| {
+                        Op::Create {
+                            creator,
+                            contribution_amount,
+                            cycle_duration,
+                            max_members,
+                            min_members,
+                        }
+                    }
+                ),
+            4 => (index(), index()).prop_map(|(group, member)| Op::Join { group, member }),
+            1 => (index(), index()).prop_map(|(group, member)| Op::Leave { group, member }),
+            1 => (index(), any::<bool>())
+                .prop_map(|(group, random)| Op::AssignPositions { group, random }),
+            2 => index().prop_map(|group| Op::Activate { group }),
+            6 => (index(), index()).prop_map(|(group, member)| Op::Contribute { group, member }),
+            3 => index().prop_map(|group| Op::Execute { group }),
+            1 => index().prop_map(|group| Op::Pause { group }),
+            1 => index().prop_map(|group| Op::Cancel { group }),
+            1 => (index(), index())
+                .prop_map(|(group, member)| Op::EmergencyWithdraw { group, member }),
+            2 => prop_oneof![0..20_000u64, any::<u64>()].prop_map(|seconds| Op::Wait { seconds }),
+        ]
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn test_random_call_sequences_keep_invariants(
+            ops in proptest::collection::vec(op(), 1..60)
+        ) {
+            let mut harness = Harness::new();
+            for op in &ops {
+                harness.apply(op);
+            }
+        }
+    }
+
+    #[test]
+    fn test_full_cycle_keeps_invariants() {
+        let mut harness = Harness::new();
+        harness.apply(&Op::Create {
+            creator: 0,
+            contribution_amount: 100,
+            cycle_duration: 3600,
+            max_members: 2,
+            min_members: 2,
+        });
+        for member in 0..2 {
+            harness.apply(&Op::Join { group: 0, member });
+        }
+        harness.apply(&Op::Activate { group: 0 });
+        for _ in 0..2 {
+            for member in 0..2 {
+                harness.apply(&Op::Contribute { group: 0, member });
+            }
+            harness.apply(&Op::Execute { group: 0 });
+        }
+        let group_id = harness.groups.get(0).unwrap();
+        assert_eq!(harness.stored_status(group_id), GroupStatus::Completed);
+    }
+
+    #[test]
+    fn test_emergency_withdrawal_keeps_invariants() {
+        let mut harness = Harness::new();
+        harness.apply(&Op::Create {
+            creator: 0,
+            contribution_amount: 100,
+            cycle_duration: 3600,
+            max_members: 3,
+            min_members: 2,
+        });
+        for member in 0..3 {
+            harness.apply(&Op::Join { group: 0, member });
+        }
+        harness.apply(&Op::Activate { group: 0 });
+        harness.apply(&Op::Contribute { group: 0, member: 1 });
+        harness.apply(&Op::Wait { seconds: 3 * 3600 });
+        harness.apply(&Op::EmergencyWithdraw { group: 0, member: 1 });
+
+        let group_id = harness.groups.get(0).unwrap();
+        assert_eq!(harness.withdrawn.get(group_id).unwrap().len(), 1);
+        assert_eq!(harness.members(group_id).len(), 2);
+    }
+}
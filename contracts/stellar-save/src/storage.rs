@@ -1,13 +1,13 @@
-use soroban_sdk::{contracttype, Address};
+//! Storage key structure for efficient data access in the Stellar-Save contract.
+//!
+//! This module defines a consistent key naming convention for all contract data,
+//! enabling efficient storage and retrieval operations. Keys are designed to:
+//! - Provide fast lookups for specific data types
+//! - Support range queries where needed
+//! - Maintain clear separation between different data categories
+//! - Enable efficient iteration over related records
 
-/// Storage key structure for efficient data access in the Stellar-Save contract.
-///
-/// This module defines a consistent key naming convention for all contract data,
-/// enabling efficient storage and retrieval operations. Keys are designed to:
-/// - Provide fast lookups for specific data types
-/// - Support range queries where needed
-/// - Maintain clear separation between different data categories
-/// - Enable efficient iteration over related records
+use soroban_sdk::{contracttype, Address, BytesN};
 
 /// Main storage key enum that encompasses all data types stored in the contract.
 ///
@@ -28,6 +28,9 @@ pub enum StorageKey {
     /// Keys for payout records.
     Payout(PayoutKey),
 
+    /// Keys for governance proposals and votes.
+    Governance(GovernanceKey),
+
     /// Keys for various counters and metadata.
     Counter(CounterKey),
 }
@@ -50,6 +53,78 @@ pub enum GroupKey {
     /// Group status: GROUP_STATUS_{id}
     /// Stores the current GroupStatus for quick status checks.
     Status(u64),
+
+    /// Group join policy: GROUP_JOIN_POLICY_{id}
+    /// Stores the JoinPolicy (Open or InviteOnly) for the group.
+    JoinPolicy(u64),
+
+    /// Group allowlist entry: GROUP_ALLOWLIST_{id}_{address}
+    /// Marks an address as allowed to join an invite-only group.
+    Allowlist(u64, Address),
+
+    /// Group invite: GROUP_INVITE_{id}_{code_hash}
+    /// Stores a one-time Invite keyed by the hash of its code.
+    Invite(u64, BytesN<32>),
+
+    /// Pending join requests: GROUP_JOIN_REQUESTS_{id}
+    /// Stores the list of JoinRequest entries awaiting an admin decision.
+    JoinRequests(u64),
+
+    /// Group activation policy: GROUP_ACTIVATION_POLICY_{id}
+    /// Stores the ActivationPolicy controlling automatic activation.
+    ActivationPolicy(u64),
+
+    /// Group token: GROUP_TOKEN_{id}
+    /// Stores the token contract address contributions are paid in.
+    Token(u64),
+
+    /// Group renewal policy: GROUP_RENEWAL_POLICY_{id}
+    /// Stores the renewal settings of a group that runs round after round.
+    RenewalPolicy(u64),
+
+    /// Group round: GROUP_ROUND_{id}
+    /// Stores the number of the round the group is running.
+    Round(u64),
+
+    /// Renewal deadline: GROUP_RENEWAL_DEADLINE_{id}
+    /// Stores when the open renewal window closes.
+    RenewalDeadline(u64),
+
+    /// Round summary: GROUP_ROUND_SUMMARY_{id}_{round}
+    /// Stores the summary of a finished round.
+    RoundSummary(u64, u32),
+
+    /// Savings club config: GROUP_SAVINGS_CLUB_{id}
+    /// Stores the club settings of a group that saves instead of rotating.
+    SavingsClub(u64),
+
+    /// Savings club penalties: GROUP_SAVINGS_PENALTIES_{id}
+    /// Stores the early withdrawal penalties a club has collected.
+    SavingsPenalties(u64),
+
+    /// Organizer fee: GROUP_ORGANIZER_FEE_{id}
+    /// Stores the organizer fee the group charges on payouts, in basis points.
+    OrganizerFee(u64),
+
+    /// Organizer fee treasury: GROUP_ORGANIZER_FEES_{id}
+    /// Stores the organizer fees collected and not yet withdrawn.
+    OrganizerFees(u64),
+
+    /// Insurance levy: GROUP_INSURANCE_LEVY_{id}
+    /// Stores the insurance levy added to each contribution, in basis points.
+    InsuranceLevy(u64),
+
+    /// Insurance reserve: GROUP_INSURANCE_RESERVE_{id}
+    /// Stores the levies collected and not yet drawn or distributed.
+    InsuranceReserve(u64),
+
+    /// Group vault: GROUP_VAULT_{id}
+    /// Stores the vault a group parks its escrow in between payouts.
+    Vault(u64),
+
+    /// Group vault position: GROUP_VAULT_POSITION_{id}
+    /// Stores the escrow parked in the vault since the last payout.
+    VaultPosition(u64),
 }
 
 /// Storage keys for member-related data.
@@ -70,6 +145,55 @@ pub enum MemberKey {
     /// Member payout eligibility: MEMBER_PAYOUT_{group_id}_{address}
     /// Tracks payout turn order and eligibility status.
     PayoutEligibility(u64, Address),
+
+    /// Member role: MEMBER_ROLE_{group_id}_{address}
+    /// Stores a granted GroupRole (Admin or Treasurer). Addresses without an
+    /// entry hold the Member role; the Owner is the group's creator.
+    Role(u64, Address),
+
+    /// Member settlement: MEMBER_SETTLEMENT_{group_id}_{address}
+    /// Stores the MemberSettlement recorded when a member was removed.
+    Settlement(u64, Address),
+
+    /// Member payout destination: MEMBER_PAYOUT_DEST_{group_id}_{address}
+    /// Stores the PayoutDestination the member's payout is sent to.
+    PayoutDestination(u64, Address),
+
+    /// Member prepayment: MEMBER_PREPAYMENT_{group_id}_{address}
+    /// Stores the Prepayment escrowed for future cycles.
+    Prepayment(u64, Address),
+
+    /// Member auto-debit enrollment: MEMBER_AUTO_DEBIT_{group_id}_{address}
+    /// Present while the member has opted in to automatic contribution pulls.
+    AutoDebit(u64, Address),
+
+    /// Member shares: MEMBER_SHARES_{group_id}_{address}
+    /// Stores the member's share units (2 units = 1 share) when not 1 share.
+    Shares(u64, Address),
+
+    /// Member renewal choice: MEMBER_RENEWAL_{group_id}_{address}
+    /// Marks a member who opted into the group's next round.
+    Renewal(u64, Address),
+
+    /// Member savings goal: MEMBER_SAVINGS_GOAL_{group_id}_{address}
+    /// Stores the amount a club member is saving toward.
+    SavingsGoal(u64, Address),
+
+    /// Member savings withdrawal: MEMBER_SAVINGS_WITHDRAWAL_{group_id}_{address}
+    /// Stores a club member's withdrawal of their savings.
+    SavingsWithdrawal(u64, Address),
+
+    /// Member insurance levies: MEMBER_INSURANCE_PAID_{group_id}_{address}
+    /// Stores the insurance levies a member has paid into the reserve.
+    InsurancePaid(u64, Address),
+
+    /// Member insurance refund: MEMBER_INSURANCE_REFUND_{group_id}_{address}
+    /// Stores the member's share of the unused reserve at group completion.
+    InsuranceRefund(u64, Address),
+
+    /// Member vault yield: MEMBER_VAULT_YIELD_{group_id}_{address}
+    /// Stores the vault yield credited to a member.
+    VaultYield(u64, Address),
 }
 
 /// Storage keys for contribution tracking.
@@ -90,6 +214,22 @@ pub enum ContributionKey {
     /// Cycle contributor count: CONTRIB_COUNT_{group_id}_{cycle}
     /// Tracks how many members have contributed in the current cycle.
     CycleCount(u64, u32),
+
+    /// Installments paid so far: CONTRIB_INSTALLMENTS_{group_id}_{cycle}_{address}
+    /// Tracks a member's partial payments until the cycle is paid in full.
+    Installments(u64, u32, Address),
+
+    /// Late contribution marker: CONTRIB_LATE_{group_id}_{cycle}_{address}
+    /// Stores when an automatic pull for the member's contribution failed.
+    Late(u64, u32, Address),
+
+    /// Past round contribution: CONTRIB_ROUND_{group_id}_{round}_{cycle}_{address}
+    /// Stores a contribution record from a finished round.
+    RoundIndividual(u64, u32, u32, Address),
+
+    /// Past round cycle total: CONTRIB_ROUND_TOTAL_{group_id}_{round}_{cycle}
+    /// Stores a cycle's contribution total from a finished round.
+    RoundCycleTotal(u64, u32, u32),
 }
 
 /// Storage keys for payout records.
@@ -110,6 +250,38 @@ pub enum PayoutKey {
     /// Payout status: PAYOUT_STATUS_{group_id}_{cycle}
     /// Tracks whether the payout has been processed for the cycle.
     Status(u64, u32),
+
+    /// Past round payout: PAYOUT_ROUND_{group_id}_{round}_{cycle}
+    /// Stores a payout record from a finished round.
+    RoundRecord(u64, u32, u32),
+}
+
+/// Storage keys for governance data.
+///
+/// Proposals are numbered per group, and each member's vote is stored
+/// separately so that outcomes can be audited after the fact.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+pub enum GovernanceKey {
+    /// Governance settings: GOV_CONFIG_{group_id}
+    /// Stores the GovernanceConfig (quorum, threshold, voting period).
+    Config(u64),
+
+    /// Proposal counter: GOV_PROPOSAL_COUNT_{group_id}
+    /// Number of proposals created so far; also the next proposal ID.
+    ProposalCount(u64),
+
+    /// Proposal: GOV_PROPOSAL_{group_id}_{proposal_id}
+    /// Stores the complete Proposal, including its tallies and status.
+    Proposal(u64, u32),
+
+    /// Vote: GOV_VOTE_{group_id}_{proposal_id}_{address}
+    /// Stores how a member voted (true = in favor).
+    Vote(u64, u32, Address),
+
+    /// Electorate: GOV_ELECTORATE_{group_id}_{proposal_id}
+    /// Stores the members who may vote on a proposal, as of its creation.
+    Electorate(u64, u32),
 }
 
 /// Storage keys for counters and global metadata.
@@ -144,6 +316,14 @@ pub enum CounterKey {
 
     /// Reentrancy protection flag for transfer operations.
     ReentrancyGuard,
+
+    /// Protocol fee treasury: COUNTER_PROTOCOL_FEES_{token}
+    /// Stores the protocol fees collected in a token and not yet withdrawn.
+    ProtocolFees(Address),
+
+    /// Allowed vault: COUNTER_ALLOWED_VAULT_{address}
+    /// Marks a vault contract the admin allows groups to use.
+    AllowedVault(Address),
 }
 
 /// Utility functions for creating storage keys with consistent formatting.
@@ -170,6 +350,97 @@ impl StorageKeyBuilder {
         StorageKey::Group(GroupKey::Status(group_id))
     }
 
+    /// Creates a key for storing the group join policy.
+    pub fn group_join_policy(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::JoinPolicy(group_id))
+    }
+
+    /// Creates a key for a group allowlist entry.
+    pub fn group_allowlist(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Group(GroupKey::Allowlist(group_id, address))
+    }
+
+    /// Creates a key for a group invite, keyed by the invite code hash.
+    pub fn group_invite(group_id: u64, code_hash: BytesN<32>) -> StorageKey {
+        StorageKey::Group(GroupKey::Invite(group_id, code_hash))
+    }
+
+    /// Creates a key for the list of pending join requests.
+    pub fn group_join_requests(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::JoinRequests(group_id))
+    }
+
+    /// Creates a key for storing the group activation policy.
+    pub fn group_activation_policy(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::ActivationPolicy(group_id))
+    }
+
+    /// Creates a key for a group's token contract address.
+    pub fn group_token(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::Token(group_id))
+    }
+
+    /// Creates a key for a group's renewal policy.
+    pub fn group_renewal_policy(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::RenewalPolicy(group_id))
+    }
+
+    /// Creates a key for the round a group is running.
+    pub fn group_round(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::Round(group_id))
+    }
+
+    /// Creates a key for when a group's renewal window closes.
+    pub fn group_renewal_deadline(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::RenewalDeadline(group_id))
+    }
+
+    /// Creates a key for the summary of a finished round.
+    pub fn group_round_summary(group_id: u64, round: u32) -> StorageKey {
+        StorageKey::Group(GroupKey::RoundSummary(group_id, round))
+    }
+
+    /// Creates a key for a group's savings club config.
+    pub fn group_savings_club(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::SavingsClub(group_id))
+    }
+
+    /// Creates a key for the early withdrawal penalties a club collected and
+    /// has not yet paid out as bonuses.
+    pub fn group_savings_penalties(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::SavingsPenalties(group_id))
+    }
+
+    /// Creates a key for a group's organizer fee.
+    pub fn group_organizer_fee(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::OrganizerFee(group_id))
+    }
+
+    /// Creates a key for a group's organizer fee treasury.
+    pub fn group_organizer_fees(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::OrganizerFees(group_id))
+    }
+
+    /// Creates a key for a group's insurance levy.
+    pub fn group_insurance_levy(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::InsuranceLevy(group_id))
+    }
+
+    /// Creates a key for a group's insurance reserve.
+    pub fn group_insurance_reserve(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::InsuranceReserve(group_id))
+    }
+
+    /// Creates a key for a group's vault setting.
+    pub fn group_vault(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::Vault(group_id))
+    }
+
+    /// Creates a key for a group's vault position.
+    pub fn group_vault_position(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::VaultPosition(group_id))
+    }
+
     // Member key builders
 
     /// Creates a key for storing member profile data.
@@ -187,6 +458,66 @@ impl StorageKeyBuilder {
         StorageKey::Member(MemberKey::PayoutEligibility(group_id, address))
     }
 
+    /// Creates a key for storing a member's granted role.
+    pub fn member_role(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::Role(group_id, address))
+    }
+
+    /// Creates a key for a removed member's settlement.
+    pub fn member_settlement(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::Settlement(group_id, address))
+    }
+
+    /// Creates a key for a member's payout destination.
+    pub fn member_payout_destination(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::PayoutDestination(group_id, address))
+    }
+
+    /// Creates a key for a member's prepaid contributions.
+    pub fn member_prepayment(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::Prepayment(group_id, address))
+    }
+
+    /// Creates a key for a member's auto-debit enrollment.
+    pub fn member_auto_debit(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::AutoDebit(group_id, address))
+    }
+
+    /// Creates a key for a member's share units.
+    pub fn member_shares(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::Shares(group_id, address))
+    }
+
+    /// Creates a key for a member's choice to join the next round.
+    pub fn member_renewal(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::Renewal(group_id, address))
+    }
+
+    /// Creates a key for a club member's savings goal.
+    pub fn member_savings_goal(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::SavingsGoal(group_id, address))
+    }
+
+    /// Creates a key for a club member's savings withdrawal.
+    pub fn member_savings_withdrawal(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::SavingsWithdrawal(group_id, address))
+    }
+
+    /// Creates a key for the insurance levies a member has paid.
+    pub fn member_insurance_paid(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::InsurancePaid(group_id, address))
+    }
+
+    /// Creates a key for a member's insurance refund.
+    pub fn member_insurance_refund(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::InsuranceRefund(group_id, address))
+    }
+
+    /// Creates a key for the vault yield credited to a member.
+    pub fn member_vault_yield(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::VaultYield(group_id, address))
+    }
+
     // Contribution key builders
 
     /// Creates a key for individual contribution records.
@@ -204,6 +535,33 @@ impl StorageKeyBuilder {
         StorageKey::Contribution(ContributionKey::CycleCount(group_id, cycle))
     }
 
+    /// Creates a key for a member's installments toward a cycle.
+    pub fn contribution_installments(group_id: u64, cycle: u32, address: Address) -> StorageKey {
+        StorageKey::Contribution(ContributionKey::Installments(group_id, cycle, address))
+    }
+
+    /// Creates a key marking a member's contribution for a cycle as late.
+    pub fn contribution_late(group_id: u64, cycle: u32, address: Address) -> StorageKey {
+        StorageKey::Contribution(ContributionKey::Late(group_id, cycle, address))
+    }
+
+    /// Creates a key for a contribution record from a finished round.
+    pub fn contribution_round_individual(
+        group_id: u64,
+        round: u32,
+        cycle: u32,
+        address: Address,
+    ) -> StorageKey {
+        StorageKey::Contribution(ContributionKey::RoundIndividual(
+            group_id, round, cycle, address,
+        ))
+    }
+
+    /// Creates a key for a cycle's contribution total from a finished round.
+    pub fn contribution_round_total(group_id: u64, round: u32, cycle: u32) -> StorageKey {
+        StorageKey::Contribution(ContributionKey::RoundCycleTotal(group_id, round, cycle))
+    }
+
     // Payout key builders
 
     /// Creates a key for payout records.
@@ -221,6 +579,38 @@ impl StorageKeyBuilder {
         StorageKey::Payout(PayoutKey::Status(group_id, cycle))
     }
 
+    /// Creates a key for a payout record from a finished round.
+    pub fn payout_round_record(group_id: u64, round: u32, cycle: u32) -> StorageKey {
+        StorageKey::Payout(PayoutKey::RoundRecord(group_id, round, cycle))
+    }
+
+    // Governance key builders
+
+    /// Creates a key for a group's governance configuration.
+    pub fn governance_config(group_id: u64) -> StorageKey {
+        StorageKey::Governance(GovernanceKey::Config(group_id))
+    }
+
+    /// Creates a key for a group's proposal counter.
+    pub fn governance_proposal_count(group_id: u64) -> StorageKey {
+        StorageKey::Governance(GovernanceKey::ProposalCount(group_id))
+    }
+
+    /// Creates a key for a proposal.
+    pub fn governance_proposal(group_id: u64, proposal_id: u32) -> StorageKey {
+        StorageKey::Governance(GovernanceKey::Proposal(group_id, proposal_id))
+    }
+
+    /// Creates a key for a member's vote on a proposal.
+    pub fn governance_vote(group_id: u64, proposal_id: u32, address: Address) -> StorageKey {
+        StorageKey::Governance(GovernanceKey::Vote(group_id, proposal_id, address))
+    }
+
+    /// Creates a key for the members who may vote on a proposal.
+    pub fn governance_electorate(group_id: u64, proposal_id: u32) -> StorageKey {
+        StorageKey::Governance(GovernanceKey::Electorate(group_id, proposal_id))
+    }
+
     // Counter key builders
 
     /// Creates a key for the next group ID counter.
@@ -257,6 +647,16 @@ impl StorageKeyBuilder {
     pub fn reentrancy_guard() -> StorageKey {
         StorageKey::Counter(CounterKey::ReentrancyGuard)
     }
+
+    /// Creates a key for the protocol fee treasury of a token.
+    pub fn protocol_fees(token: Address) -> StorageKey {
+        StorageKey::Counter(CounterKey::ProtocolFees(token))
+    }
+
+    /// Creates a key marking a vault as allowed.
+    pub fn allowed_vault(vault: Address) -> StorageKey {
+        StorageKey::Counter(CounterKey::AllowedVault(vault))
+    }
 }
 
 /// Constants for storage key prefixes used in string representations.
@@ -273,6 +673,60 @@ pub mod key_prefixes {
     /// Group status prefix
     pub const GROUP_STATUS: &str = "GROUP_STATUS";
 
+    /// Group join policy prefix
+    pub const GROUP_JOIN_POLICY: &str = "GROUP_JOIN_POLICY";
+
+    /// Group allowlist prefix
+    pub const GROUP_ALLOWLIST: &str = "GROUP_ALLOWLIST";
+
+    /// Group invite prefix
+    pub const GROUP_INVITE: &str = "GROUP_INVITE";
+
+    /// Group join requests prefix
+    pub const GROUP_JOIN_REQUESTS: &str = "GROUP_JOIN_REQUESTS";
+
+    /// Group activation policy prefix
+    pub const GROUP_ACTIVATION_POLICY: &str = "GROUP_ACTIVATION_POLICY";
+
+    /// Group token prefix
+    pub const GROUP_TOKEN: &str = "GROUP_TOKEN";
+
+    /// Group renewal policy prefix
+    pub const GROUP_RENEWAL_POLICY: &str = "GROUP_RENEWAL_POLICY";
+
+    /// Group round prefix
+    pub const GROUP_ROUND: &str = "GROUP_ROUND";
+
+    /// Renewal deadline prefix
+    pub const GROUP_RENEWAL_DEADLINE: &str = "GROUP_RENEWAL_DEADLINE";
+
+    /// Round summary prefix
+    pub const GROUP_ROUND_SUMMARY: &str = "GROUP_ROUND_SUMMARY";
+
+    /// Savings club config prefix
+    pub const GROUP_SAVINGS_CLUB: &str = "GROUP_SAVINGS_CLUB";
+
+    /// Savings club penalties prefix
+    pub const GROUP_SAVINGS_PENALTIES: &str = "GROUP_SAVINGS_PENALTIES";
+
+    /// Organizer fee prefix
+    pub const GROUP_ORGANIZER_FEE: &str = "GROUP_ORGANIZER_FEE";
+
+    /// Organizer fee treasury prefix
+    pub const GROUP_ORGANIZER_FEES: &str = "GROUP_ORGANIZER_FEES";
+
+    /// Insurance levy prefix
+    pub const GROUP_INSURANCE_LEVY: &str = "GROUP_INSURANCE_LEVY";
+
+    /// Insurance reserve prefix
+    pub const GROUP_INSURANCE_RESERVE: &str = "GROUP_INSURANCE_RESERVE";
+
+    /// Group vault prefix
+    pub const GROUP_VAULT: &str = "GROUP_VAULT";
+
+    /// Group vault position prefix
+    pub const GROUP_VAULT_POSITION: &str = "GROUP_VAULT_POSITION";
+
     /// Member profile prefix
     pub const MEMBER: &str = "MEMBER";
 
@@ -282,6 +736,42 @@ pub mod key_prefixes {
     /// Member payout eligibility prefix
     pub const MEMBER_PAYOUT: &str = "MEMBER_PAYOUT";
 
+    /// Member role prefix
+    pub const MEMBER_ROLE: &str = "MEMBER_ROLE";
+
+    /// Member settlement prefix
+    pub const MEMBER_SETTLEMENT: &str = "MEMBER_SETTLEMENT";
+
+    /// Member payout destination prefix
+    pub const MEMBER_PAYOUT_DEST: &str = "MEMBER_PAYOUT_DEST";
+
+    /// Member prepayment prefix
+    pub const MEMBER_PREPAYMENT: &str = "MEMBER_PREPAYMENT";
+
+    /// Member auto-debit prefix
+    pub const MEMBER_AUTO_DEBIT: &str = "MEMBER_AUTO_DEBIT";
+
+    /// Member shares prefix
+    pub const MEMBER_SHARES: &str = "MEMBER_SHARES";
+
+    /// Member renewal choice prefix
+    pub const MEMBER_RENEWAL: &str = "MEMBER_RENEWAL";
+
+    /// Member savings goal prefix
+    pub const MEMBER_SAVINGS_GOAL: &str = "MEMBER_SAVINGS_GOAL";
+
+    /// Member savings withdrawal prefix
+    pub const MEMBER_SAVINGS_WITHDRAWAL: &str = "MEMBER_SAVINGS_WITHDRAWAL";
+
+    /// Member insurance levies prefix
+    pub const MEMBER_INSURANCE_PAID: &str = "MEMBER_INSURANCE_PAID";
+
+    /// Member insurance refund prefix
+    pub const MEMBER_INSURANCE_REFUND: &str = "MEMBER_INSURANCE_REFUND";
+
+    /// Member vault yield prefix
+    pub const MEMBER_VAULT_YIELD: &str = "MEMBER_VAULT_YIELD";
+
     /// Individual contribution prefix
     pub const CONTRIB: &str = "CONTRIB";
 
@@ -291,6 +781,18 @@ pub mod key_prefixes {
     /// Cycle contributor count prefix
     pub const CONTRIB_COUNT: &str = "CONTRIB_COUNT";
 
+    /// Contribution installments prefix
+    pub const CONTRIB_INSTALLMENTS: &str = "CONTRIB_INSTALLMENTS";
+
+    /// Late contribution prefix
+    pub const CONTRIB_LATE: &str = "CONTRIB_LATE";
+
+    /// Past round contribution prefix
+    pub const CONTRIB_ROUND: &str = "CONTRIB_ROUND";
+
+    /// Past round cycle total prefix
+    pub const CONTRIB_ROUND_TOTAL: &str = "CONTRIB_ROUND_TOTAL";
+
     /// Payout record prefix
     pub const PAYOUT: &str = "PAYOUT";
 
@@ -300,6 +802,24 @@ pub mod key_prefixes {
     /// Payout status prefix
     pub const PAYOUT_STATUS: &str = "PAYOUT_STATUS";
 
+    /// Past round payout prefix
+    pub const PAYOUT_ROUND: &str = "PAYOUT_ROUND";
+
+    /// Governance configuration prefix
+    pub const GOV_CONFIG: &str = "GOV_CONFIG";
+
+    /// Proposal counter prefix
+    pub const GOV_PROPOSAL_COUNT: &str = "GOV_PROPOSAL_COUNT";
+
+    /// Proposal prefix
+    pub const GOV_PROPOSAL: &str = "GOV_PROPOSAL";
+
+    /// Vote prefix
+    pub const GOV_VOTE: &str = "GOV_VOTE";
+
+    /// Electorate prefix
+    pub const GOV_ELECTORATE: &str = "GOV_ELECTORATE";
+
     /// Counter prefix
     pub const COUNTER: &str = "COUNTER";
 }
@@ -325,11 +845,37 @@ mod tests {
         let data_key = StorageKeyBuilder::group_data(group_id);
         let members_key = StorageKeyBuilder::group_members(group_id);
         let status_key = StorageKeyBuilder::group_status(group_id);
+        let token_key = StorageKeyBuilder::group_token(group_id);
 
         // Verify the keys are different
         assert_ne!(data_key, members_key);
         assert_ne!(data_key, status_key);
         assert_ne!(members_key, status_key);
+        assert_ne!(data_key, token_key);
+        assert_ne!(
+            StorageKeyBuilder::group_round(group_id),
+            StorageKeyBuilder::group_renewal_deadline(group_id)
+        );
+        assert_ne!(
+            StorageKeyBuilder::group_round_summary(group_id, 0),
+            StorageKeyBuilder::group_round_summary(group_id, 1)
+        );
+        assert_ne!(
+            StorageKeyBuilder::group_savings_club(group_id),
+            StorageKeyBuilder::group_savings_penalties(group_id)
+        );
+        assert_ne!(
+            StorageKeyBuilder::group_organizer_fee(group_id),
+            StorageKeyBuilder::group_organizer_fees(group_id)
+        );
+        assert_ne!(
+            StorageKeyBuilder::group_insurance_levy(group_id),
+            StorageKeyBuilder::group_insurance_reserve(group_id)
+        );
+        assert_ne!(
+            StorageKeyBuilder::group_vault(group_id),
+            StorageKeyBuilder::group_vault_position(group_id)
+        );
 
         // Verify they contain the correct group ID
         match data_key {
@@ -338,6 +884,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_invite_key_builders() {
+        let env = Env::default();
+        let group_id = 7;
+        let address = Address::generate(&env);
+        let code_hash = BytesN::from_array(&env, &[1u8; 32]);
+
+        let policy_key = StorageKeyBuilder::group_join_policy(group_id);
+        let allowlist_key = StorageKeyBuilder::group_allowlist(group_id, address.clone());
+        let invite_key = StorageKeyBuilder::group_invite(group_id, code_hash.clone());
+        let requests_key = StorageKeyBuilder::group_join_requests(group_id);
+        let activation_key = StorageKeyBuilder::group_activation_policy(group_id);
+
+        // Verify the keys are different
+        assert_ne!(policy_key, allowlist_key);
+        assert_ne!(policy_key, invite_key);
+        assert_ne!(allowlist_key, invite_key);
+        assert_ne!(policy_key, requests_key);
+        assert_ne!(policy_key, activation_key);
+
+        // Verify they contain the correct data
+        match invite_key {
+            StorageKey::Group(GroupKey::Invite(id, hash)) => {
+                assert_eq!(id, group_id);
+                assert_eq!(hash, code_hash);
+            }
+            _ => panic!("Wrong key type"),
+        }
+    }
+
     #[test]
     fn test_member_key_builders() {
         let env = Env::default();
@@ -347,11 +923,48 @@ mod tests {
         let profile_key = StorageKeyBuilder::member_profile(group_id, address.clone());
         let contrib_key = StorageKeyBuilder::member_contribution_status(group_id, address.clone());
         let payout_key = StorageKeyBuilder::member_payout_eligibility(group_id, address.clone());
+        let role_key = StorageKeyBuilder::member_role(group_id, address.clone());
+        let settlement_key = StorageKeyBuilder::member_settlement(group_id, address.clone());
+        let destination_key =
+            StorageKeyBuilder::member_payout_destination(group_id, address.clone());
+        let prepayment_key = StorageKeyBuilder::member_prepayment(group_id, address.clone());
 
         // Verify all keys are different
         assert_ne!(profile_key, contrib_key);
         assert_ne!(profile_key, payout_key);
         assert_ne!(contrib_key, payout_key);
+        assert_ne!(profile_key, role_key);
+        assert_ne!(payout_key, role_key);
+        assert_ne!(profile_key, settlement_key);
+        assert_ne!(role_key, settlement_key);
+        assert_ne!(payout_key, destination_key);
+        assert_ne!(settlement_key, destination_key);
+        assert_ne!(contrib_key, prepayment_key);
+        assert_ne!(destination_key, prepayment_key);
+        assert_ne!(
+            prepayment_key,
+            StorageKeyBuilder::member_auto_debit(group_id, address.clone())
+        );
+        assert_ne!(
+            profile_key,
+            StorageKeyBuilder::member_shares(group_id, address.clone())
+        );
+        assert_ne!(
+            profile_key,
+            StorageKeyBuilder::member_renewal(group_id, address.clone())
+        );
+        assert_ne!(
+            StorageKeyBuilder::member_savings_goal(group_id, address.clone()),
+            StorageKeyBuilder::member_savings_withdrawal(group_id, address.clone())
+        );
+        assert_ne!(
+            StorageKeyBuilder::member_insurance_paid(group_id, address.clone()),
+            StorageKeyBuilder::member_insurance_refund(group_id, address.clone())
+        );
+        assert_ne!(
+            StorageKeyBuilder::member_insurance_refund(group_id, address.clone()),
+            StorageKeyBuilder::member_vault_yield(group_id, address.clone())
+        );
 
         // Verify they contain the correct data
         match profile_key {
@@ -374,11 +987,26 @@ mod tests {
             StorageKeyBuilder::contribution_individual(group_id, cycle, address.clone());
         let total_key = StorageKeyBuilder::contribution_cycle_total(group_id, cycle);
         let count_key = StorageKeyBuilder::contribution_cycle_count(group_id, cycle);
+        let installments_key =
+            StorageKeyBuilder::contribution_installments(group_id, cycle, address.clone());
 
         // Verify all keys are different
         assert_ne!(individual_key, total_key);
         assert_ne!(individual_key, count_key);
         assert_ne!(total_key, count_key);
+        assert_ne!(individual_key, installments_key);
+        assert_ne!(
+            installments_key,
+            StorageKeyBuilder::contribution_late(group_id, cycle, address.clone())
+        );
+        assert_ne!(
+            StorageKeyBuilder::contribution_round_individual(group_id, 0, cycle, address.clone()),
+            StorageKeyBuilder::contribution_round_individual(group_id, 1, cycle, address.clone())
+        );
+        assert_ne!(
+            total_key,
+            StorageKeyBuilder::contribution_round_total(group_id, 0, cycle)
+        );
 
         // Verify they contain the correct data
         match individual_key {
@@ -404,6 +1032,10 @@ mod tests {
         assert_ne!(record_key, recipient_key);
         assert_ne!(record_key, status_key);
         assert_ne!(recipient_key, status_key);
+        assert_ne!(
+            record_key,
+            StorageKeyBuilder::payout_round_record(group_id, 0, cycle)
+        );
 
         // Verify they contain the correct data
         match record_key {
@@ -415,6 +1047,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_governance_key_builders() {
+        let env = Env::default();
+        let group_id = 3;
+        let proposal_id = 1;
+        let address = Address::generate(&env);
+
+        let config_key = StorageKeyBuilder::governance_config(group_id);
+        let count_key = StorageKeyBuilder::governance_proposal_count(group_id);
+        let proposal_key = StorageKeyBuilder::governance_proposal(group_id, proposal_id);
+        let vote_key = StorageKeyBuilder::governance_vote(group_id, proposal_id, address.clone());
+        let electorate_key = StorageKeyBuilder::governance_electorate(group_id, proposal_id);
+
+        // Verify all keys are different
+        assert_ne!(config_key, count_key);
+        assert_ne!(config_key, proposal_key);
+        assert_ne!(proposal_key, vote_key);
+        assert_ne!(proposal_key, electorate_key);
+
+        // Verify they contain the correct data
+        match vote_key {
+            StorageKey::Governance(GovernanceKey::Vote(id, p, addr)) => {
+                assert_eq!(id, group_id);
+                assert_eq!(p, proposal_id);
+                assert_eq!(addr, address);
+            }
+            _ => panic!("Wrong key type"),
+        }
+    }
+
     #[test]
     fn test_counter_key_builders() {
         let next_id_key = StorageKeyBuilder::next_group_id();
@@ -422,6 +1084,9 @@ mod tests {
         let active_groups_key = StorageKeyBuilder::active_groups();
         let total_members_key = StorageKeyBuilder::total_members();
         let version_key = StorageKeyBuilder::contract_version();
+        let env = Env::default();
+        let token = Address::generate(&env);
+        let protocol_fees_key = StorageKeyBuilder::protocol_fees(token.clone());
 
         // Verify all keys are different
         let keys = [
@@ -430,6 +1095,7 @@ mod tests {
             &active_groups_key,
             &total_members_key,
             &version_key,
+            &protocol_fees_key,
         ];
 
         for i in 0..keys.len() {
@@ -442,6 +1108,18 @@ mod tests {
             }
         }
 
+        let vault = Address::generate(&env);
+        assert_ne!(
+            StorageKeyBuilder::allowed_vault(vault.clone()),
+            StorageKeyBuilder::allowed_vault(Address::generate(&env))
+        );
+        assert_ne!(StorageKeyBuilder::allowed_vault(token), protocol_fees_key);
+        assert_ne!(
+            StorageKeyBuilder::protocol_fees(vault.clone()),
+            protocol_fees_key
+        );
+        assert_ne!(StorageKeyBuilder::allowed_vault(vault), protocol_fees_key);
+
         // Verify key types
         match next_id_key {
             StorageKey::Counter(CounterKey::NextGroupId) => {}
@@ -0,0 +1,174 @@
+use soroban_sdk::{contractclient, contracttype, Address, Env};
+
+/// Interface a yield vault must implement for groups to park escrow in it.
+///
+/// Vaults hold a single token asset, issue shares for deposits and redeem
+/// them for the deposit plus any yield earned since. The Stellar-Save
+/// contract deposits and withdraws as itself, so a vault sees one owner for
+/// all groups using it; the contract tracks each group's shares on its own.
+#[contractclient(name = "VaultClient")]
+pub trait VaultInterface {
+    /// Returns the token the vault holds.
+    fn asset(env: Env) -> Address;
+
+    /// Pulls `amount` of the asset from `from` with `transfer_from`, under an
+    /// allowance `from` gave the vault, and returns the shares issued for it.
+    fn deposit(env: Env, from: Address, amount: i128) -> i128;
+
+    /// Redeems `shares` held by `to`, transfers what they are worth to `to`
+    /// and returns that amount.
+    fn withdraw(env: Env, to: Address, shares: i128) -> i128;
+
+    /// Returns what the shares held by `owner` are currently worth.
+    fn balance(env: Env, owner: Address) -> i128;
+}
+
+/// Where the yield a group's parked escrow earns goes.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum YieldDestination {
+    /// Credited to members in proportion to their shares.
+    Members,
+
+    /// Added to the group's insurance reserve.
+    InsuranceReserve,
+}
+
+/// A group's opt-in to park its escrow in a vault between payouts.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupVault {
+    /// Vault contract, which must be on the admin's allowlist.
+    pub vault: Address,
+
+    /// Where the yield goes at each payout.
+    pub yield_to: YieldDestination,
+}
+
+/// Escrow a group has parked in its vault since the last payout.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct VaultPosition {
+    /// Contributions deposited, in stroops.
+    pub principal: i128,
+
+    /// Vault shares issued for them.
+    pub shares: i128,
+}
+
+impl VaultPosition {
+    /// Returns the position after depositing `amount` for `shares`, or
+    /// `None` on overflow.
+    pub fn deposit(&self, amount: i128, shares: i128) -> Option<Self> {
+        Some(Self {
+            principal: self.principal.checked_add(amount)?,
+            shares: self.shares.checked_add(shares)?,
+        })
+    }
+
+    /// Returns the yield earned when the position redeems for `redeemed`.
+    /// A vault loss yields nothing rather than a negative amount.
+    pub fn yield_on(&self, redeemed: i128) -> i128 {
+        (redeemed - self.principal).max(0)
+    }
+}
+
+/// Mock vault for tests. Shares are priced from the vault's balance of its
+/// asset, so minting the asset to the vault adds yield to them.
+#[cfg(test)]
+pub mod mock {
+    use soroban_sdk::{contract, contractimpl, contracttype, token, Address, Env};
+
+    #[contracttype]
+    #[derive(Clone)]
+    enum MockVaultKey {
+        Asset,
+        TotalShares,
+        Shares(Address),
+    }
+
+    #[contract]
+    pub struct MockVault;
+
+    fn get(env: &Env, key: &MockVaultKey) -> i128 {
+        env.storage().instance().get(key).unwrap_or(0)
+    }
+
+    fn set(env: &Env, key: &MockVaultKey, value: i128) {
+        env.storage().instance().set(key, &value);
+    }
+
+    fn asset(env: &Env) -> token::TokenClient<'_> {
+        let asset: Address = env.storage().instance().get(&MockVaultKey::Asset).unwrap();
+        token::TokenClient::new(env, &asset)
+    }
+
+    #[contractimpl]
+    impl MockVault {
+        pub fn __constructor(env: Env, asset: Address) {
+            env.storage().instance().set(&MockVaultKey::Asset, &asset);
+        }
+
+        pub fn asset(env: Env) -> Address {
+            env.storage().instance().get(&MockVaultKey::Asset).unwrap()
+        }
+
+        pub fn deposit(env: Env, from: Address, amount: i128) -> i128 {
+            from.require_auth();
+            let vault = env.current_contract_address();
+            let assets = asset(&env).balance(&vault);
+            let total = get(&env, &MockVaultKey::TotalShares);
+            let shares = if total == 0 {
+                amount
+            } else {
+                amount * total / assets
+            };
+            asset(&env).transfer_from(&vault, &from, &vault, &amount);
+
+            let owner_key = MockVaultKey::Shares(from);
+            set(&env, &owner_key, get(&env, &owner_key) + shares);
+            set(&env, &MockVaultKey::TotalShares, total + shares);
+            shares
+        }
+
+        pub fn withdraw(env: Env, to: Address, shares: i128) -> i128 {
+            to.require_auth();
+            let vault = env.current_contract_address();
+            let assets = asset(&env).balance(&vault);
+            let total = get(&env, &MockVaultKey::TotalShares);
+            let amount = shares * assets / total;
+
+            let owner_key = MockVaultKey::Shares(to.clone());
+            set(&env, &owner_key, get(&env, &owner_key) - shares);
+            set(&env, &MockVaultKey::TotalShares, total - shares);
+            asset(&env).transfer(&vault, &to, &amount);
+            amount
+        }
+
+        pub fn balance(env: Env, owner: Address) -> i128 {
+            let total = get(&env, &MockVaultKey::TotalShares);
+            if total == 0 {
+                return 0;
+            }
+            let assets = asset(&env).balance(&env.current_contract_address());
+            get(&env, &MockVaultKey::Shares(owner)) * assets / total
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vault_position_yield() {
+        let position = VaultPosition::default().deposit(100, 100).unwrap();
+        let position = position.deposit(100, 90).unwrap();
+
+        assert_eq!(position.principal, 200);
+        assert_eq!(position.shares, 190);
+        assert_eq!(position.yield_on(230), 30);
+        assert_eq!(position.yield_on(180), 0);
+        assert!(position.deposit(i128::MAX, 0).is_none());
+    }
+}
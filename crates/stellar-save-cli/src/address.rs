@@ -0,0 +1,41 @@
+use std::str::FromStr;
+
+use soroban_sdk::xdr::{ScAddress, ScVal};
+use soroban_sdk::{Address, Env, TryFromVal, Val};
+
+use crate::error::{CliError, Result};
+
+/// Parses a `G...` account or `C...` contract strkey.
+pub fn parse_address(env: &Env, strkey: &str) -> Result<Address> {
+    let invalid = || CliError::InvalidAddress(strkey.to_string());
+    let address = ScAddress::from_str(strkey).map_err(|_| invalid())?;
+    let val = Val::try_from_val(env, &ScVal::Address(address)).map_err(|_| invalid())?;
+    Address::try_from_val(env, &val).map_err(|_| invalid())
+}
+
+/// Formats an address as its strkey.
+pub fn strkey(env: &Env, address: &Address) -> String {
+    match ScVal::try_from_val(env, &address.to_val()) {
+        Ok(ScVal::Address(address)) => address.to_string(),
+        _ => String::from("<invalid address>"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_address_round_trip() {
+        let env = Env::default();
+        let account = "GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCWN7";
+        assert_eq!(
+            strkey(&env, &parse_address(&env, account).unwrap()),
+            account
+        );
+        assert!(matches!(
+            parse_address(&env, "GNOTANADDRESS"),
+            Err(CliError::InvalidAddress(_))
+        ));
+    }
+}
@@ -0,0 +1,146 @@
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+
+use crate::output::Format;
+
+/// Manage Stellar-Save groups.
+#[derive(Debug, Parser)]
+#[command(name = "stellar-save", version)]
+pub struct Cli {
+    /// Network config file.
+    #[arg(long, global = true, default_value = "environments.toml")]
+    pub config: PathBuf,
+
+    /// Network from the config file to use.
+    #[arg(long, global = true, default_value = "sandbox")]
+    pub network: String,
+
+    /// Contract to call; overrides the network's `contract_id`.
+    #[arg(long, global = true)]
+    pub contract_id: Option<String>,
+
+    /// Output format.
+    #[arg(long, global = true, value_enum, default_value_t = Format::Table)]
+    pub format: Format,
+
+    /// Simulate the call and report its cost without committing anything.
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Subcommand)]
+pub enum Command {
+    /// Create a group.
+    Create {
+        /// Address creating the group.
+        #[arg(long)]
+        creator: String,
+
+        /// Contribution per member per cycle, in stroops.
+        #[arg(long)]
+        amount: i128,
+
+        /// Cycle length in seconds.
+        #[arg(long)]
+        cycle_duration: u64,
+
+        #[arg(long)]
+        max_members: u32,
+
+        #[arg(long, default_value_t = 2)]
+        min_members: u32,
+
+        /// Token contract contributions are paid in; members approve this
+        /// contract to spend it.
+        #[arg(long)]
+        token: Option<String>,
+    },
+
+    /// Join a group.
+    Join {
+        #[arg(long)]
+        group: u64,
+
+        #[arg(long)]
+        member: String,
+    },
+
+    /// Pay the current cycle's contribution for a member.
+    Contribute {
+        #[arg(long)]
+        group: u64,
+
+        #[arg(long)]
+        member: String,
+
+        /// Address paying, if not the member.
+        #[arg(long)]
+        payer: Option<String>,
+    },
+
+    /// Activate a pending group.
+    Activate {
+        #[arg(long)]
+        group: u64,
+
+        /// Group admin activating it.
+        #[arg(long)]
+        caller: String,
+    },
+
+    /// Propose pausing an active group; members then vote on it.
+    Pause {
+        #[arg(long)]
+        group: u64,
+
+        #[arg(long)]
+        proposer: String,
+    },
+
+    /// Propose cancelling a group; members then vote on it.
+    Cancel {
+        #[arg(long)]
+        group: u64,
+
+        #[arg(long)]
+        proposer: String,
+    },
+
+    /// Pay out the current cycle and move the group to the next one.
+    ExecuteCycle {
+        #[arg(long)]
+        group: u64,
+
+        /// Group treasurer executing the payout.
+        #[arg(long)]
+        caller: String,
+    },
+
+    /// Show a group and its current cycle.
+    Status {
+        #[arg(long)]
+        group: u64,
+    },
+
+    /// Show who is paid in which cycle, and when.
+    Schedule {
+        #[arg(long)]
+        group: u64,
+    },
+
+    /// Show past payouts.
+    History {
+        #[arg(long)]
+        group: u64,
+
+        #[arg(long, default_value_t = 0)]
+        offset: u32,
+
+        #[arg(long, default_value_t = 20)]
+        limit: u32,
+    },
+}
@@ -0,0 +1,335 @@
+use serde_json::{json, Value};
+use stellar_save_client::{Backend, Client, ProposalAction};
+
+use crate::address::{parse_address, strkey};
+use crate::cli::Command;
+use crate::error::{CliError, Result};
+use crate::output::{Output, Row};
+
+/// Runs a command against `client`.
+///
+/// With `dry_run`, every call is simulated and the cost of the last one is
+/// added to the output.
+pub fn run<B: Backend>(client: &mut Client<B>, command: &Command, dry_run: bool) -> Result<Output> {
+    if !dry_run {
+        return execute(client, command, false);
+    }
+
+    let mut simulation = client.simulation();
+    let output = execute(&mut simulation, command, true)?;
+    match (output, simulation.backend().last_cost()) {
+        (Output::Record(mut row), Some(cost)) => {
+            row.push(("dry_run", json!(true)));
+            row.push(("cpu_instructions", json!(cost.cpu_instructions)));
+            row.push(("memory_bytes", json!(cost.memory_bytes)));
+            Ok(Output::Record(row))
+        }
+        (output, _) => Ok(output),
+    }
+}
+
+/// i128 amounts don't fit JSON numbers, so they're printed as strings.
+fn amount(value: i128) -> Value {
+    json!(value.to_string())
+}
+
+fn execute<B: Backend>(client: &mut Client<B>, command: &Command, dry_run: bool) -> Result<Output> {
+    let env = client.env().clone();
+    let address = |strkey: &str| parse_address(&env, strkey);
+
+    let output = match command {
+        Command::Create {
+            creator,
+            amount: contribution,
+            cycle_duration,
+            max_members,
+            min_members,
+            token,
+        } => {
+            let creator = address(creator)?;
+            let group_id = client.create_group(
+                &creator,
+                *contribution,
+                *cycle_duration,
+                *max_members,
+                *min_members,
+                &None,
+            )?;
+            if let Some(token) = token {
+                client.set_group_token(group_id, &creator, &address(token)?)?;
+            }
+            Output::Record(vec![("group_id", json!(group_id)), ("token", json!(token))])
+        }
+        Command::Join { group, member } => {
+            client.join_group(*group, &address(member)?)?;
+            Output::Record(vec![("group_id", json!(group)), ("member", json!(member))])
+        }
+        Command::Contribute {
+            group,
+            member,
+            payer,
+        } => {
+            let payer = payer.as_ref().unwrap_or(member);
+            client.contribute_for(*group, &address(payer)?, &address(member)?)?;
+            Output::Record(vec![
+                ("group_id", json!(group)),
+                ("member", json!(member)),
+                ("payer", json!(payer)),
+            ])
+        }
+        Command::Activate { group, caller } => {
+            client.activate_group(*group, &address(caller)?)?;
+            Output::Record(vec![
+                ("group_id", json!(group)),
+                ("status", json!("Active")),
+            ])
+        }
+        Command::Pause { group, proposer } => {
+            propose(client, *group, &address(proposer)?, ProposalAction::Pause)?
+        }
+        Command::Cancel { group, proposer } => {
+            propose(client, *group, &address(proposer)?, ProposalAction::Cancel)?
+        }
+        Command::ExecuteCycle { group, caller } => {
+            let caller = address(caller)?;
+
+            // 1. The payout goes to the first slot of the current cycle
+            let pool = client.get_pool_info(*group)?;
+            let recipient = client
+                .get_payout_slots(*group)?
+                .iter()
+                .find(|slot| slot.cycle == pool.cycle)
+                .map(|slot| slot.recipient.clone())
+                .ok_or(CliError::NoPayout(*group))?;
+
+            // 2. Pay it out, then move on; a dry run stops at the payout
+            client.transfer_payout(
+                *group,
+                &caller,
+                &recipient,
+                pool.total_pool_amount,
+                pool.cycle,
+            )?;
+            let next_cycle = if dry_run {
+                Value::Null
+            } else {
+                json!(client.advance_cycle(*group, &caller)?)
+            };
+
+            Output::Record(vec![
+                ("group_id", json!(group)),
+                ("cycle", json!(pool.cycle)),
+                ("recipient", json!(strkey(&env, &recipient))),
+                ("amount", amount(pool.total_pool_amount)),
+                ("next_cycle", next_cycle),
+            ])
+        }
+        Command::Status { group } => {
+            let info = client.get_group(*group)?;
+            let mut row: Row = vec![
+                ("group_id", json!(info.id)),
+                ("status", json!(info.status.to_string())),
+                ("creator", json!(strkey(&env, &info.creator))),
+                ("contribution_amount", amount(info.contribution_amount)),
+                ("cycle_duration", json!(info.cycle_duration)),
+                ("members", json!(info.member_count)),
+                ("max_members", json!(info.max_members)),
+                ("current_cycle", json!(info.current_cycle)),
+            ];
+            if info.started {
+                let pool = client.get_pool_info(*group)?;
+                row.extend([
+                    ("contributors", json!(pool.contributors_count)),
+                    ("collected", amount(pool.current_contributions)),
+                    ("pool", amount(pool.total_pool_amount)),
+                    ("cycle_complete", json!(pool.is_cycle_complete)),
+                    (
+                        "deadline",
+                        json!(client.get_contribution_deadline(*group, info.current_cycle)?),
+                    ),
+                ]);
+            }
+            Output::Record(row)
+        }
+        Command::Schedule { group } => Output::List(
+            client
+                .get_payout_schedule(*group)?
+                .iter()
+                .map(|entry| {
+                    vec![
+                        ("cycle", json!(entry.cycle)),
+                        ("recipient", json!(strkey(&env, &entry.recipient))),
+                        ("payout_date", json!(entry.payout_date)),
+                    ]
+                })
+                .collect(),
+        ),
+        Command::History {
+            group,
+            offset,
+            limit,
+        } => Output::List(
+            client
+                .get_payout_history(*group, *offset, *limit)?
+                .iter()
+                .map(|record| {
+                    vec![
+                        ("cycle", json!(record.cycle_number)),
+                        ("recipient", json!(strkey(&env, &record.recipient))),
+                        ("amount", amount(record.amount)),
+                        ("net_amount", amount(record.net_amount())),
+                        ("paid_at", json!(record.timestamp)),
+                    ]
+                })
+                .collect(),
+        ),
+    };
+    Ok(output)
+}
+
+fn propose<B: Backend>(
+    client: &mut Client<B>,
+    group: u64,
+    proposer: &soroban_sdk::Address,
+    action: ProposalAction,
+) -> Result<Output> {
+    let name = format!("{:?}", action);
+    let proposal_id = client.create_proposal(group, proposer, &action)?;
+    Ok(Output::Record(vec![
+        ("group_id", json!(group)),
+        ("proposal_id", json!(proposal_id)),
+        ("action", json!(name)),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::token::{StellarAssetClient, TokenClient};
+    use soroban_sdk::Address;
+    use stellar_save_client::mock::MockBackend;
+
+    fn account(client: &Client<MockBackend>) -> String {
+        strkey(client.env(), &Address::generate(client.env()))
+    }
+
+    /// Registers a token and funds `accounts` with it, each approving the
+    /// contract to pull their contributions.
+    fn token(client: &Client<MockBackend>, accounts: &[&String]) -> String {
+        let env = client.env();
+        let admin = Address::generate(env);
+        let token = env.register_stellar_asset_contract_v2(admin).address();
+        let expiration = env.ledger().sequence() + 100_000;
+        for account in accounts {
+            let account = parse_address(env, account).unwrap();
+            StellarAssetClient::new(env, &token).mint(&account, &1_000_000_000);
+            TokenClient::new(env, &token).approve(
+                &account,
+                client.backend().contract_id(),
+                &1_000_000_000,
+                &expiration,
+            );
+        }
+        strkey(env, &token)
+    }
+
+    fn field<'a>(output: &'a Output, name: &str) -> &'a Value {
+        match output {
+            Output::Record(row) => &row.iter().find(|(key, _)| *key == name).unwrap().1,
+            Output::List(_) => panic!("expected a record"),
+        }
+    }
+
+    #[test]
+    fn test_commands_run_a_group_through_a_cycle() {
+        let mut client = MockBackend::new().into_client();
+        let creator = account(&client);
+        let member = account(&client);
+        let token = token(&client, &[&creator, &member]);
+
+        let create = Command::Create {
+            creator: creator.clone(),
+            amount: 10_000_000,
+            cycle_duration: 604_800,
+            max_members: 2,
+            min_members: 2,
+            token: Some(token.clone()),
+        };
+        let created = run(&mut client, &create, false).unwrap();
+        let group = field(&created, "group_id").as_u64().unwrap();
+        assert_eq!(field(&created, "token"), &json!(token));
+
+        for address in [&creator, &member] {
+            let join = Command::Join {
+                group,
+                member: address.clone(),
+            };
+            run(&mut client, &join, false).unwrap();
+        }
+        let activate = Command::Activate {
+            group,
+            caller: creator.clone(),
+        };
+        run(&mut client, &activate, false).unwrap();
+
+        for address in [&creator, &member] {
+            let contribute = Command::Contribute {
+                group,
+                member: address.clone(),
+                payer: None,
+            };
+            run(&mut client, &contribute, false).unwrap();
+        }
+
+        let status = run(&mut client, &Command::Status { group }, false).unwrap();
+        assert_eq!(field(&status, "status"), &json!("Active"));
+        assert_eq!(field(&status, "collected"), &json!("20000000"));
+        assert_eq!(field(&status, "cycle_complete"), &json!(true));
+
+        // A dry run reports the cost and leaves the group untouched
+        let execute = Command::ExecuteCycle {
+            group,
+            caller: creator.clone(),
+        };
+        let simulated = run(&mut client, &execute, true).unwrap();
+        assert_eq!(field(&simulated, "dry_run"), &json!(true));
+        assert_eq!(field(&simulated, "next_cycle"), &Value::Null);
+        assert!(field(&simulated, "cpu_instructions").as_u64().unwrap() > 0);
+        let history = Command::History {
+            group,
+            offset: 0,
+            limit: 20,
+        };
+        assert_eq!(
+            run(&mut client, &history, false).unwrap(),
+            Output::List(vec![])
+        );
+
+        let executed = run(&mut client, &execute, false).unwrap();
+        assert_eq!(field(&executed, "amount"), &json!("20000000"));
+        assert_eq!(field(&executed, "next_cycle"), &json!(1));
+
+        match run(&mut client, &history, false).unwrap() {
+            Output::List(rows) => {
+                assert_eq!(rows.len(), 1);
+                assert_eq!(rows[0][1].1, *field(&executed, "recipient"));
+            }
+            Output::Record(_) => panic!("expected a list"),
+        }
+    }
+
+    #[test]
+    fn test_contract_errors_are_reported() {
+        let mut client = MockBackend::new().into_client();
+        let err = run(&mut client, &Command::Status { group: 42 }, false).unwrap_err();
+        assert!(matches!(err, CliError::Client(_)));
+
+        let join = Command::Join {
+            group: 1,
+            member: "not-an-address".to_string(),
+        };
+        let err = run(&mut client, &join, false).unwrap_err();
+        assert!(matches!(err, CliError::InvalidAddress(_)));
+    }
+}
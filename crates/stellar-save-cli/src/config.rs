@@ -0,0 +1,89 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::error::{CliError, Result};
+
+/// One network table of `environments.toml`.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq)]
+pub struct Network {
+    pub rpc_url: Option<String>,
+    pub network_passphrase: Option<String>,
+
+    /// Deployed Stellar-Save contract on this network.
+    pub contract_id: Option<String>,
+
+    /// Ledger file of a local sandbox, relative to the config file. Networks
+    /// with one run the contract in-process instead of over RPC.
+    pub sandbox: Option<PathBuf>,
+}
+
+/// Networks by name, as in `environments.toml`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Config {
+    pub networks: BTreeMap<String, Network>,
+    dir: PathBuf,
+}
+
+impl Config {
+    /// Reads the config file at `path`.
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| CliError::Config(format!("{}: {}", path.display(), err)))?;
+        let dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        Self::parse(&contents, dir)
+    }
+
+    /// Parses config contents; relative paths are resolved against `dir`.
+    pub fn parse(contents: &str, dir: PathBuf) -> Result<Self> {
+        let networks =
+            toml::from_str(contents).map_err(|err| CliError::Config(err.message().to_string()))?;
+        Ok(Self { networks, dir })
+    }
+
+    /// Returns the named network.
+    pub fn network(&self, name: &str) -> Result<&Network> {
+        self.networks
+            .get(name)
+            .ok_or_else(|| CliError::Config(format!("no network named `{}`", name)))
+    }
+
+    /// Returns the sandbox ledger path of a network, if it has one.
+    pub fn sandbox_path(&self, network: &Network) -> Option<PathBuf> {
+        network.sandbox.as_ref().map(|path| self.dir.join(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_reads_network_tables() {
+        let config = Config::parse(
+            r#"
+            [testnet]
+            rpc_url = "https://soroban-testnet.stellar.org"
+            network_passphrase = "Test SDF Network ; September 2015"
+
+            [sandbox]
+            sandbox = "state/ledger.json"
+            contract_id = "CBSXZQRW3KNV6MOZSWD7HGR6JPEFUU7F2DC3ED45FG3SVC4NZG7EGD46"
+            "#,
+            PathBuf::from("/etc/stellar-save"),
+        )
+        .unwrap();
+
+        let testnet = config.network("testnet").unwrap();
+        assert_eq!(testnet.sandbox, None);
+        assert_eq!(config.sandbox_path(testnet), None);
+        let sandbox = config.network("sandbox").unwrap();
+        assert_eq!(
+            config.sandbox_path(sandbox),
+            Some(PathBuf::from("/etc/stellar-save/state/ledger.json"))
+        );
+        assert!(config.network("mainnet").is_err());
+    }
+}
@@ -0,0 +1,50 @@
+use std::fmt;
+
+use stellar_save_client::ClientError;
+
+/// Errors reported to the operator.
+#[derive(Debug)]
+pub enum CliError {
+    /// The config file is missing, unreadable or invalid.
+    Config(String),
+
+    /// The network can't be used by this CLI.
+    Network(String),
+
+    /// An address argument is not a valid strkey.
+    InvalidAddress(String),
+
+    /// Reading or writing the sandbox ledger failed.
+    Sandbox(String),
+
+    /// The group has no payout slot for its current cycle.
+    NoPayout(u64),
+
+    /// The contract call failed.
+    Client(ClientError),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Config(reason) => write!(f, "config: {}", reason),
+            CliError::Network(reason) => write!(f, "network: {}", reason),
+            CliError::InvalidAddress(address) => write!(f, "invalid address `{}`", address),
+            CliError::Sandbox(reason) => write!(f, "sandbox: {}", reason),
+            CliError::NoPayout(group) => {
+                write!(f, "group {} has no recipient for its current cycle", group)
+            }
+            CliError::Client(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+impl From<ClientError> for CliError {
+    fn from(err: ClientError) -> Self {
+        CliError::Client(err)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, CliError>;
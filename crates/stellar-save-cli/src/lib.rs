@@ -0,0 +1,23 @@
+//! Operator CLI for Stellar-Save.
+//!
+//! Parses commands ([`cli`]), runs them through the typed client
+//! ([`commands`]) and prints the result as a table or JSON ([`output`]).
+//! Networks come from `environments.toml`; a network with a `sandbox` path
+//! runs the contract in-process against a local ledger file
+//! ([`sandbox::SandboxBackend`]). Talking to a remote RPC is left to a
+//! [`stellar_save_client::Backend`] supplied by the embedding service.
+
+pub mod address;
+pub mod cli;
+pub mod commands;
+pub mod config;
+pub mod error;
+pub mod output;
+pub mod sandbox;
+
+pub use cli::{Cli, Command};
+pub use commands::run;
+pub use config::{Config, Network};
+pub use error::{CliError, Result};
+pub use output::{Format, Output};
+pub use sandbox::SandboxBackend;
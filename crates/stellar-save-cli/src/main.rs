@@ -0,0 +1,45 @@
+use std::process::ExitCode;
+
+use clap::Parser;
+use stellar_save_cli::{run, Cli, CliError, Config, Output, Result, SandboxBackend};
+use stellar_save_client::Client;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    match execute(&cli) {
+        Ok(output) => {
+            println!("{}", output.render(cli.format));
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn execute(cli: &Cli) -> Result<Output> {
+    let config = Config::load(&cli.config)?;
+    let network = config.network(&cli.network)?;
+
+    let contract_id = cli
+        .contract_id
+        .as_ref()
+        .or(network.contract_id.as_ref())
+        .ok_or_else(|| {
+            CliError::Config(format!(
+                "no contract_id for `{}`; set one or pass --contract-id",
+                cli.network
+            ))
+        })?;
+    let path = config.sandbox_path(network).ok_or_else(|| {
+        CliError::Network(format!(
+            "`{}` has no sandbox; remote RPC networks are not supported yet",
+            cli.network
+        ))
+    })?;
+
+    let backend = SandboxBackend::open(&path, contract_id)?;
+    let mut client = Client::with_env(backend.env().clone(), backend);
+    run(&mut client, &cli.command, cli.dry_run)
+}
@@ -0,0 +1,126 @@
+use clap::ValueEnum;
+use serde_json::{Map, Value};
+
+/// How command results are printed.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum Format {
+    /// Aligned columns for people.
+    #[default]
+    Table,
+
+    /// JSON for scripts.
+    Json,
+}
+
+/// Named fields, in display order.
+pub type Row = Vec<(&'static str, Value)>;
+
+/// A command result.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Output {
+    /// A single result, shown as field/value pairs.
+    Record(Row),
+
+    /// Several results of the same shape, shown one per line.
+    List(Vec<Row>),
+}
+
+impl Output {
+    /// Renders the output in `format`.
+    pub fn render(&self, format: Format) -> String {
+        match format {
+            Format::Table => self.to_table(),
+            Format::Json => serde_json::to_string_pretty(&self.to_json()).unwrap_or_default(),
+        }
+    }
+
+    /// Returns the output as a JSON object, or an array of objects.
+    pub fn to_json(&self) -> Value {
+        let object = |row: &Row| {
+            Value::Object(
+                row.iter()
+                    .map(|(key, value)| (key.to_string(), value.clone()))
+                    .collect::<Map<_, _>>(),
+            )
+        };
+        match self {
+            Output::Record(row) => object(row),
+            Output::List(rows) => Value::Array(rows.iter().map(object).collect()),
+        }
+    }
+
+    fn to_table(&self) -> String {
+        let (header, rows): (Vec<String>, Vec<Vec<String>>) = match self {
+            Output::Record(row) => (
+                vec!["FIELD".to_string(), "VALUE".to_string()],
+                row.iter()
+                    .map(|(key, value)| vec![key.to_string(), cell(value)])
+                    .collect(),
+            ),
+            Output::List(rows) => match rows.first() {
+                Some(first) => (
+                    first.iter().map(|(key, _)| key.to_uppercase()).collect(),
+                    rows.iter()
+                        .map(|row| row.iter().map(|(_, value)| cell(value)).collect())
+                        .collect(),
+                ),
+                None => return "(none)".to_string(),
+            },
+        };
+
+        let mut widths: Vec<usize> = header.iter().map(String::len).collect();
+        for row in &rows {
+            for (width, value) in widths.iter_mut().zip(row) {
+                *width = (*width).max(value.len());
+            }
+        }
+
+        std::iter::once(&header)
+            .chain(&rows)
+            .map(|row| {
+                row.iter()
+                    .zip(&widths)
+                    .map(|(value, width)| format!("{:<width$}", value, width = width))
+                    .collect::<Vec<_>>()
+                    .join("  ")
+                    .trim_end()
+                    .to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => "-".to_string(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_render_table_and_json() {
+        let output = Output::List(vec![
+            vec![("cycle", json!(0)), ("recipient", json!("GA"))],
+            vec![("cycle", json!(10)), ("recipient", Value::Null)],
+        ]);
+        assert_eq!(
+            output.render(Format::Table),
+            "CYCLE  RECIPIENT\n0      GA\n10     -"
+        );
+        assert_eq!(
+            output.to_json(),
+            json!([{"cycle": 0, "recipient": "GA"}, {"cycle": 10, "recipient": null}])
+        );
+
+        let record = Output::Record(vec![("group_id", json!(1))]);
+        assert_eq!(record.render(Format::Table), "FIELD     VALUE\ngroup_id  1");
+        assert_eq!(Output::List(vec![]).render(Format::Table), "(none)");
+    }
+}
@@ -0,0 +1,82 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use soroban_sdk::testutils::{EnvTestConfig, Ledger, Snapshot};
+use soroban_sdk::xdr::ScVal;
+use soroban_sdk::Env;
+use stellar_save_client::mock::{self, MockBackend};
+use stellar_save_client::{Backend, Cost, Failure, Invocation};
+
+use crate::address::parse_address;
+use crate::error::{CliError, Result};
+
+/// Local sandbox: the contract runs in-process against a ledger kept in a
+/// JSON file, which is saved after every submitted call.
+///
+/// Each session starts at the current wall-clock time, so cycle deadlines
+/// pass in real time. Auth is not checked.
+pub struct SandboxBackend {
+    inner: MockBackend,
+    path: PathBuf,
+}
+
+impl SandboxBackend {
+    /// Opens the sandbox ledger at `path`, or starts an empty one if it
+    /// doesn't exist yet, with the contract at `contract_id`.
+    pub fn open(path: &Path, contract_id: &str) -> Result<Self> {
+        let env = if path.exists() {
+            let snapshot = Snapshot::read_file(path)
+                .map_err(|err| CliError::Sandbox(format!("{}: {}", path.display(), err)))?;
+            mock::env_from_snapshot(snapshot)
+        } else {
+            Env::new_with_config(EnvTestConfig {
+                capture_snapshot_at_drop: false,
+            })
+        };
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        let ledger = env.ledger().get();
+        env.ledger().with_mut(|info| {
+            info.timestamp = ledger.timestamp.max(now);
+            info.sequence_number = ledger.sequence_number + 1;
+        });
+
+        let contract_id = parse_address(&env, contract_id)?;
+        Ok(Self {
+            inner: MockBackend::with_env(env, &contract_id),
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// Returns the sandbox environment.
+    pub fn env(&self) -> &Env {
+        self.inner.env()
+    }
+
+    fn save(&self) -> Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(|err| CliError::Sandbox(err.to_string()))?;
+        }
+        self.env()
+            .to_snapshot()
+            .write_file(&self.path)
+            .map_err(|err| CliError::Sandbox(format!("{}: {}", self.path.display(), err)))
+    }
+}
+
+impl Backend for SandboxBackend {
+    fn submit(&mut self, call: &Invocation) -> std::result::Result<ScVal, Failure> {
+        let value = self.inner.submit(call)?;
+        self.save()
+            .map_err(|err| Failure::Transport(err.to_string()))?;
+        Ok(value)
+    }
+
+    fn simulate(&mut self, call: &Invocation) -> std::result::Result<(ScVal, Cost), Failure> {
+        self.inner.simulate(call)
+    }
+}
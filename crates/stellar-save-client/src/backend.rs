@@ -0,0 +1,91 @@
+use soroban_sdk::xdr::ScVal;
+
+/// A contract call, with arguments already encoded as XDR values.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Invocation {
+    /// Entry point name, e.g. `create_group`.
+    pub function: String,
+
+    /// Arguments in entry point order, without `env`.
+    pub args: std::vec::Vec<ScVal>,
+}
+
+/// Why a backend couldn't return a value for a call.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Failure {
+    /// The contract returned an error code (a `StellarSaveError`).
+    Contract(u32),
+
+    /// The host aborted the call, e.g. a failed auth check or a panic.
+    Host(String),
+
+    /// The call never reached the contract, e.g. the RPC node was down.
+    Transport(String),
+}
+
+/// Resources a simulated call consumed.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Cost {
+    pub cpu_instructions: u64,
+    pub memory_bytes: u64,
+}
+
+/// Where a [`Client`](crate::Client) sends its calls.
+///
+/// Services implement this over their RPC transport; [`MockBackend`]
+/// implements it over the soroban test environment.
+///
+/// [`MockBackend`]: crate::mock::MockBackend
+pub trait Backend {
+    /// Submits the call and returns the contract's return value.
+    fn submit(&mut self, call: &Invocation) -> Result<ScVal, Failure>;
+
+    /// Runs the call without committing anything and returns the value it
+    /// would return along with its cost.
+    fn simulate(&mut self, call: &Invocation) -> Result<(ScVal, Cost), Failure>;
+}
+
+impl<B: Backend + ?Sized> Backend for &mut B {
+    fn submit(&mut self, call: &Invocation) -> Result<ScVal, Failure> {
+        (**self).submit(call)
+    }
+
+    fn simulate(&mut self, call: &Invocation) -> Result<(ScVal, Cost), Failure> {
+        (**self).simulate(call)
+    }
+}
+
+/// Backend that simulates every call it's given, so a client over it runs
+/// entry points as a dry run. Created by [`Client::simulation`].
+///
+/// [`Client::simulation`]: crate::Client::simulation
+pub struct Simulation<'a, B: Backend> {
+    inner: &'a mut B,
+    last_cost: Option<Cost>,
+}
+
+impl<'a, B: Backend> Simulation<'a, B> {
+    pub(crate) fn new(inner: &'a mut B) -> Self {
+        Self {
+            inner,
+            last_cost: None,
+        }
+    }
+
+    /// Returns the cost of the last successful simulated call.
+    pub fn last_cost(&self) -> Option<Cost> {
+        self.last_cost
+    }
+}
+
+impl<B: Backend> Backend for Simulation<'_, B> {
+    fn submit(&mut self, call: &Invocation) -> Result<ScVal, Failure> {
+        let (value, cost) = self.inner.simulate(call)?;
+        self.last_cost = Some(cost);
+        Ok(value)
+    }
+
+    fn simulate(&mut self, call: &Invocation) -> Result<(ScVal, Cost), Failure> {
+        self.inner.simulate(call)
+    }
+}
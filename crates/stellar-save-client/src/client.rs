@@ -0,0 +1,170 @@
+use soroban_sdk::xdr::ScVal;
+use soroban_sdk::{Env, TryFromVal, Val, Vec};
+
+use crate::backend::{Backend, Invocation, Simulation};
+use crate::error::{ClientError, Result};
+
+/// Typed client for the Stellar-Save contract.
+///
+/// Arguments are encoded and return values decoded through the client's
+/// [`Env`], so addresses and other host values passed to it must be created
+/// from [`Client::env`]. The entry point methods live in `entry_points.rs`.
+pub struct Client<B: Backend> {
+    pub(crate) env: Env,
+    backend: B,
+}
+
+impl<B: Backend> Client<B> {
+    /// Creates a client that sends its calls to `backend`.
+    pub fn new(backend: B) -> Self {
+        Self::with_env(Env::default(), backend)
+    }
+
+    /// Creates a client that encodes values through an existing `env`.
+    pub fn with_env(env: Env, backend: B) -> Self {
+        Self { env, backend }
+    }
+
+    /// Returns the environment used to build arguments and decode results.
+    pub fn env(&self) -> &Env {
+        &self.env
+    }
+
+    /// Returns the backend.
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Returns the backend mutably.
+    pub fn backend_mut(&mut self) -> &mut B {
+        &mut self.backend
+    }
+
+    /// Returns a client whose calls are all simulated against this client's
+    /// backend: they return what the entry point would return, but nothing
+    /// is committed. The cost of the last call is available from
+    /// `backend().last_cost()`.
+    pub fn simulation(&mut self) -> Client<Simulation<'_, B>> {
+        Client {
+            env: self.env.clone(),
+            backend: Simulation::new(&mut self.backend),
+        }
+    }
+
+    /// Calls an entry point by name and decodes its return value. Used by the
+    /// typed methods; also handy for entry points added after this client.
+    pub fn invoke<T: TryFromVal<Env, Val>>(&mut self, function: &str, args: Vec<Val>) -> Result<T> {
+        // 1. Encode the arguments as XDR
+        let args = args
+            .iter()
+            .map(|arg| ScVal::try_from_val(&self.env, &arg))
+            .collect::<std::result::Result<_, _>>()
+            .map_err(|err| ClientError::Conversion(format!("{:?}", err)))?;
+        let call = Invocation {
+            function: function.to_string(),
+            args,
+        };
+
+        // 2. Send the call; contract error codes decode to StellarSaveError
+        let value = self.backend.submit(&call)?;
+
+        // 3. Decode the return value
+        let value = Val::try_from_val(&self.env, &value)
+            .map_err(|err| ClientError::Conversion(format!("{:?}", err)))?;
+        T::try_from_val(&self.env, &value).map_err(|_| {
+            ClientError::Conversion(format!("unexpected return type from {}", function))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::token::{StellarAssetClient, TokenClient};
+    use soroban_sdk::Address;
+    use stellar_save::{ErrorCategory, GroupStatus, StellarSaveError};
+
+    use crate::error::ClientError;
+    use crate::mock::MockBackend;
+
+    #[test]
+    fn test_typed_calls_round_trip_through_backend() {
+        let mut client = MockBackend::new().into_client();
+        let creator = Address::generate(client.env());
+        let member = Address::generate(client.env());
+
+        let env = client.env().clone();
+        let token = env
+            .register_stellar_asset_contract_v2(creator.clone())
+            .address();
+        StellarAssetClient::new(&env, &token).mint(&member, &100);
+        let contract_id = client.backend().contract_id().clone();
+        TokenClient::new(&env, &token).approve(&member, &contract_id, &100, &1000);
+
+        let group_id = client
+            .create_group(&creator, 100, 3600, 2, 2, &None)
+            .unwrap();
+        client.set_group_token(group_id, &creator, &token).unwrap();
+        client.join_group(group_id, &creator).unwrap();
+        client.join_group(group_id, &member).unwrap();
+        client.activate_group(group_id, &creator).unwrap();
+
+        let group = client.get_group(group_id).unwrap();
+        assert_eq!(group.creator, creator);
+        assert_eq!(group.status, GroupStatus::Active);
+        assert_eq!(client.get_member_count(group_id).unwrap(), 2);
+
+        client.contribute_for(group_id, &member, &member).unwrap();
+        let pool = client.get_pool_info(group_id).unwrap();
+        assert_eq!(pool.contribution_amount, 100);
+        assert_eq!(
+            client
+                .get_member_total_contributions(group_id, &member)
+                .unwrap(),
+            100
+        );
+        assert_eq!(TokenClient::new(&env, &token).balance(&contract_id), 100);
+    }
+
+    #[test]
+    fn test_contract_errors_decode() {
+        let mut client = MockBackend::new().into_client();
+        let creator = Address::generate(client.env());
+
+        let err = client.get_group(7).unwrap_err();
+        assert_eq!(err, ClientError::Contract(StellarSaveError::GroupNotFound));
+        assert_eq!(err.category(), Some(ErrorCategory::Group));
+
+        let group_id = client
+            .create_group(&creator, 100, 3600, 3, 2, &None)
+            .unwrap();
+        client.join_group(group_id, &creator).unwrap();
+        let err = client.join_group(group_id, &creator).unwrap_err();
+        assert_eq!(err.code(), Some(StellarSaveError::AlreadyMember.code()));
+        assert_eq!(client.validate_cycle_duration(3600), Ok(()));
+    }
+
+    #[test]
+    fn test_simulation_does_not_commit() {
+        let mut client = MockBackend::new().into_client();
+        let creator = Address::generate(client.env());
+
+        let mut simulation = client.simulation();
+        let group_id = simulation
+            .create_group(&creator, 100, 3600, 3, 2, &None)
+            .unwrap();
+        assert_eq!(group_id, 1);
+        assert!(simulation.backend().last_cost().unwrap().cpu_instructions > 0);
+        let err = simulation.get_group(99).unwrap_err();
+        assert_eq!(err.contract_error(), Some(StellarSaveError::GroupNotFound));
+
+        assert_eq!(client.get_total_groups().unwrap(), 0);
+        assert_eq!(
+            client
+                .create_group(&creator, 100, 3600, 3, 2, &None)
+                .unwrap(),
+            1
+        );
+        assert_eq!(client.get_total_groups().unwrap(), 1);
+    }
+}
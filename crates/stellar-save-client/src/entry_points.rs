@@ -0,0 +1,1355 @@
+//! Typed wrappers for every `StellarSaveContract` entry point.
+//!
+//! Each method takes the entry point's arguments minus `env`, in the same
+//! order, and returns its value or a decoded [`ClientError`]. See the
+//! contract's docs for what each entry point does.
+
+use soroban_sdk::{vec, Address, Bytes, BytesN, IntoVal, Vec};
+use stellar_save::{
+    ActivationPolicy, AssignmentMode, ContractConfig, ContributionRecord, CycleSchedule,
+    GovernanceConfig, Group, GroupRole, GroupStatus, GroupVault, Invite, JoinPolicy, JoinRequest,
+    MemberSettlement, PayoutDestination, PayoutRecord, PayoutScheduleEntry, PayoutSlot, PoolInfo,
+    Prepayment, Proposal, ProposalAction, ProposalStatus, RenewalPolicy, RoundSummary,
+    SavingsClubConfig, SavingsWithdrawal, VaultPosition,
+};
+
+use crate::backend::Backend;
+use crate::client::Client;
+use crate::error::Result;
+
+impl<B: Backend> Client<B> {
+    pub fn validate_contribution_amount(&mut self, group_id: u64, amount: i128) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            amount.into_val(&self.env),
+        ];
+        self.invoke("validate_contribution_amount", args)
+    }
+
+    pub fn validate_cycle_duration(&mut self, cycle_duration: u64) -> Result<()> {
+        let args = vec![&self.env, cycle_duration.into_val(&self.env)];
+        self.invoke("validate_cycle_duration", args)
+    }
+
+    pub fn validate_contribution_range(&mut self, amount: i128) -> Result<()> {
+        let args = vec![&self.env, amount.into_val(&self.env)];
+        self.invoke("validate_contribution_range", args)
+    }
+
+    pub fn contribute_for(
+        &mut self,
+        group_id: u64,
+        payer: &Address,
+        member: &Address,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            payer.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("contribute_for", args)
+    }
+
+    pub fn pay_installment(
+        &mut self,
+        group_id: u64,
+        member: &Address,
+        amount: i128,
+    ) -> Result<i128> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+            amount.into_val(&self.env),
+        ];
+        self.invoke("pay_installment", args)
+    }
+
+    pub fn get_outstanding_balance(
+        &mut self,
+        group_id: u64,
+        cycle: u32,
+        member: &Address,
+    ) -> Result<i128> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            cycle.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("get_outstanding_balance", args)
+    }
+
+    pub fn get_pool_info(&mut self, group_id: u64) -> Result<PoolInfo> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_pool_info", args)
+    }
+
+    pub fn set_member_shares(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        member: &Address,
+        share_units: u32,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            member.into_val(&self.env),
+            share_units.into_val(&self.env),
+        ];
+        self.invoke("set_member_shares", args)
+    }
+
+    pub fn get_member_shares(&mut self, group_id: u64, member: &Address) -> Result<u32> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("get_member_shares", args)
+    }
+
+    pub fn get_payout_slots(&mut self, group_id: u64) -> Result<Vec<PayoutSlot>> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_payout_slots", args)
+    }
+
+    pub fn prepay(&mut self, group_id: u64, member: &Address, cycles: u32) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+            cycles.into_val(&self.env),
+        ];
+        self.invoke("prepay", args)
+    }
+
+    pub fn refund_prepayment(&mut self, group_id: u64, member: &Address) -> Result<i128> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("refund_prepayment", args)
+    }
+
+    pub fn get_prepayment(
+        &mut self,
+        group_id: u64,
+        member: &Address,
+    ) -> Result<Option<Prepayment>> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("get_prepayment", args)
+    }
+
+    pub fn advance_cycle(&mut self, group_id: u64, caller: &Address) -> Result<u32> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+        ];
+        self.invoke("advance_cycle", args)
+    }
+
+    pub fn set_renewal_policy(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        policy: &RenewalPolicy,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            policy.into_val(&self.env),
+        ];
+        self.invoke("set_renewal_policy", args)
+    }
+
+    pub fn get_renewal_policy(&mut self, group_id: u64) -> Result<Option<RenewalPolicy>> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_renewal_policy", args)
+    }
+
+    pub fn set_renewal_choice(
+        &mut self,
+        group_id: u64,
+        member: &Address,
+        renew: bool,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+            renew.into_val(&self.env),
+        ];
+        self.invoke("set_renewal_choice", args)
+    }
+
+    pub fn is_renewing(&mut self, group_id: u64, member: &Address) -> Result<bool> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("is_renewing", args)
+    }
+
+    pub fn get_renewal_deadline(&mut self, group_id: u64) -> Result<Option<u64>> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_renewal_deadline", args)
+    }
+
+    pub fn start_next_round(&mut self, group_id: u64, caller: &Address) -> Result<GroupStatus> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+        ];
+        self.invoke("start_next_round", args)
+    }
+
+    pub fn get_current_round(&mut self, group_id: u64) -> Result<u32> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_current_round", args)
+    }
+
+    pub fn get_round_summary(&mut self, group_id: u64, round: u32) -> Result<Option<RoundSummary>> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            round.into_val(&self.env),
+        ];
+        self.invoke("get_round_summary", args)
+    }
+
+    pub fn get_round_contribution(
+        &mut self,
+        group_id: u64,
+        round: u32,
+        cycle: u32,
+        member: &Address,
+    ) -> Result<Option<ContributionRecord>> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            round.into_val(&self.env),
+            cycle.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("get_round_contribution", args)
+    }
+
+    pub fn get_round_payout(
+        &mut self,
+        group_id: u64,
+        round: u32,
+        cycle: u32,
+    ) -> Result<Option<PayoutRecord>> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            round.into_val(&self.env),
+            cycle.into_val(&self.env),
+        ];
+        self.invoke("get_round_payout", args)
+    }
+
+    pub fn set_savings_club(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        config: &SavingsClubConfig,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            config.into_val(&self.env),
+        ];
+        self.invoke("set_savings_club", args)
+    }
+
+    pub fn get_savings_club(&mut self, group_id: u64) -> Result<Option<SavingsClubConfig>> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_savings_club", args)
+    }
+
+    pub fn set_savings_goal(&mut self, group_id: u64, member: &Address, goal: i128) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+            goal.into_val(&self.env),
+        ];
+        self.invoke("set_savings_goal", args)
+    }
+
+    pub fn get_savings_goal(&mut self, group_id: u64, member: &Address) -> Result<Option<i128>> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("get_savings_goal", args)
+    }
+
+    pub fn get_savings_balance(&mut self, group_id: u64, member: &Address) -> Result<i128> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("get_savings_balance", args)
+    }
+
+    pub fn get_club_savings(&mut self, group_id: u64) -> Result<i128> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_club_savings", args)
+    }
+
+    pub fn withdraw_savings(&mut self, group_id: u64, member: &Address) -> Result<i128> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("withdraw_savings", args)
+    }
+
+    pub fn get_savings_withdrawal(
+        &mut self,
+        group_id: u64,
+        member: &Address,
+    ) -> Result<Option<SavingsWithdrawal>> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("get_savings_withdrawal", args)
+    }
+
+    pub fn set_organizer_fee(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        fee_bps: u32,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            fee_bps.into_val(&self.env),
+        ];
+        self.invoke("set_organizer_fee", args)
+    }
+
+    pub fn get_organizer_fee(&mut self, group_id: u64) -> Result<u32> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_organizer_fee", args)
+    }
+
+    pub fn get_protocol_fees(&mut self, token: &Address) -> Result<i128> {
+        let args = vec![&self.env, token.into_val(&self.env)];
+        self.invoke("get_protocol_fees", args)
+    }
+
+    pub fn get_organizer_fees(&mut self, group_id: u64) -> Result<i128> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_organizer_fees", args)
+    }
+
+    pub fn withdraw_protocol_fees(&mut self, token: &Address) -> Result<i128> {
+        let args = vec![&self.env, token.into_val(&self.env)];
+        self.invoke("withdraw_protocol_fees", args)
+    }
+
+    pub fn withdraw_organizer_fees(&mut self, group_id: u64, caller: &Address) -> Result<i128> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+        ];
+        self.invoke("withdraw_organizer_fees", args)
+    }
+
+    pub fn set_insurance_levy(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        levy_bps: u32,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            levy_bps.into_val(&self.env),
+        ];
+        self.invoke("set_insurance_levy", args)
+    }
+
+    pub fn get_insurance_levy(&mut self, group_id: u64) -> Result<u32> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_insurance_levy", args)
+    }
+
+    pub fn get_insurance_reserve(&mut self, group_id: u64) -> Result<i128> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_insurance_reserve", args)
+    }
+
+    pub fn get_insurance_paid(&mut self, group_id: u64, member: &Address) -> Result<i128> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("get_insurance_paid", args)
+    }
+
+    pub fn get_insurance_refund(&mut self, group_id: u64, member: &Address) -> Result<i128> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("get_insurance_refund", args)
+    }
+
+    pub fn allow_vault(&mut self, vault: &Address) -> Result<()> {
+        let args = vec![&self.env, vault.into_val(&self.env)];
+        self.invoke("allow_vault", args)
+    }
+
+    pub fn disallow_vault(&mut self, vault: &Address) -> Result<()> {
+        let args = vec![&self.env, vault.into_val(&self.env)];
+        self.invoke("disallow_vault", args)
+    }
+
+    pub fn is_vault_allowed(&mut self, vault: &Address) -> Result<bool> {
+        let args = vec![&self.env, vault.into_val(&self.env)];
+        self.invoke("is_vault_allowed", args)
+    }
+
+    pub fn set_group_vault(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        setting: &GroupVault,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            setting.into_val(&self.env),
+        ];
+        self.invoke("set_group_vault", args)
+    }
+
+    pub fn clear_group_vault(&mut self, group_id: u64, caller: &Address) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+        ];
+        self.invoke("clear_group_vault", args)
+    }
+
+    pub fn get_group_vault(&mut self, group_id: u64) -> Result<Option<GroupVault>> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_group_vault", args)
+    }
+
+    pub fn get_vault_position(&mut self, group_id: u64) -> Result<VaultPosition> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_vault_position", args)
+    }
+
+    pub fn get_vault_yield(&mut self, group_id: u64, member: &Address) -> Result<i128> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("get_vault_yield", args)
+    }
+
+    pub fn claim_vault_yield(&mut self, group_id: u64, member: &Address) -> Result<i128> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("claim_vault_yield", args)
+    }
+
+    pub fn set_group_token(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        token: &Address,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            token.into_val(&self.env),
+        ];
+        self.invoke("set_group_token", args)
+    }
+
+    pub fn get_group_token(&mut self, group_id: u64) -> Result<Option<Address>> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_group_token", args)
+    }
+
+    pub fn enroll_auto_debit(&mut self, group_id: u64, member: &Address) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("enroll_auto_debit", args)
+    }
+
+    pub fn cancel_auto_debit(&mut self, group_id: u64, member: &Address) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("cancel_auto_debit", args)
+    }
+
+    pub fn is_auto_debit_enrolled(&mut self, group_id: u64, member: &Address) -> Result<bool> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("is_auto_debit_enrolled", args)
+    }
+
+    pub fn is_contribution_late(
+        &mut self,
+        group_id: u64,
+        cycle: u32,
+        member: &Address,
+    ) -> Result<bool> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            cycle.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("is_contribution_late", args)
+    }
+
+    pub fn get_member_count(&mut self, group_id: u64) -> Result<u32> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_member_count", args)
+    }
+
+    pub fn update_config(&mut self, new_config: &ContractConfig) -> Result<()> {
+        let args = vec![&self.env, new_config.into_val(&self.env)];
+        self.invoke("update_config", args)
+    }
+
+    pub fn create_group(
+        &mut self,
+        creator: &Address,
+        contribution_amount: i128,
+        cycle_duration: u64,
+        max_members: u32,
+        min_members: u32,
+        activation_policy: &Option<ActivationPolicy>,
+    ) -> Result<u64> {
+        let args = vec![
+            &self.env,
+            creator.into_val(&self.env),
+            contribution_amount.into_val(&self.env),
+            cycle_duration.into_val(&self.env),
+            max_members.into_val(&self.env),
+            min_members.into_val(&self.env),
+            activation_policy.into_val(&self.env),
+        ];
+        self.invoke("create_group", args)
+    }
+
+    pub fn update_group(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        new_contribution: i128,
+        new_duration: u64,
+        new_max_members: u32,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            new_contribution.into_val(&self.env),
+            new_duration.into_val(&self.env),
+            new_max_members.into_val(&self.env),
+        ];
+        self.invoke("update_group", args)
+    }
+
+    pub fn get_group(&mut self, group_id: u64) -> Result<Group> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_group", args)
+    }
+
+    pub fn has_received_payout(&mut self, group_id: u64, member_address: &Address) -> Result<bool> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member_address.into_val(&self.env),
+        ];
+        self.invoke("has_received_payout", args)
+    }
+
+    pub fn is_payout_due(&mut self, group_id: u64) -> Result<bool> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("is_payout_due", args)
+    }
+
+    pub fn get_payout_position(&mut self, group_id: u64, member_address: &Address) -> Result<u32> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member_address.into_val(&self.env),
+        ];
+        self.invoke("get_payout_position", args)
+    }
+
+    pub fn validate_payout_recipient(
+        &mut self,
+        group_id: u64,
+        recipient: &Address,
+    ) -> Result<bool> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            recipient.into_val(&self.env),
+        ];
+        self.invoke("validate_payout_recipient", args)
+    }
+
+    pub fn get_total_paid_out(&mut self, group_id: u64) -> Result<i128> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_total_paid_out", args)
+    }
+
+    pub fn get_group_balance(&mut self, group_id: u64) -> Result<i128> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_group_balance", args)
+    }
+
+    pub fn get_payout_history(
+        &mut self,
+        group_id: u64,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<PayoutRecord>> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            offset.into_val(&self.env),
+            limit.into_val(&self.env),
+        ];
+        self.invoke("get_payout_history", args)
+    }
+
+    pub fn get_member_payout(
+        &mut self,
+        group_id: u64,
+        member_address: &Address,
+    ) -> Result<Option<PayoutRecord>> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member_address.into_val(&self.env),
+        ];
+        self.invoke("get_member_payout", args)
+    }
+
+    pub fn get_payout(&mut self, group_id: u64, cycle: u32) -> Result<PayoutRecord> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            cycle.into_val(&self.env),
+        ];
+        self.invoke("get_payout", args)
+    }
+
+    pub fn get_payout_schedule(&mut self, group_id: u64) -> Result<Vec<PayoutScheduleEntry>> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_payout_schedule", args)
+    }
+
+    pub fn is_complete(&mut self, group_id: u64) -> Result<bool> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("is_complete", args)
+    }
+
+    pub fn get_payout_queue(&mut self, group_id: u64) -> Result<Vec<Address>> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_payout_queue", args)
+    }
+
+    pub fn assign_payout_positions(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        mode: &AssignmentMode,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            mode.into_val(&self.env),
+        ];
+        self.invoke("assign_payout_positions", args)
+    }
+
+    pub fn set_payout_destination(
+        &mut self,
+        group_id: u64,
+        member: &Address,
+        destination: &PayoutDestination,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+            destination.into_val(&self.env),
+        ];
+        self.invoke("set_payout_destination", args)
+    }
+
+    pub fn clear_payout_destination(&mut self, group_id: u64, member: &Address) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("clear_payout_destination", args)
+    }
+
+    pub fn get_payout_destination(
+        &mut self,
+        group_id: u64,
+        member: &Address,
+    ) -> Result<Option<PayoutDestination>> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("get_payout_destination", args)
+    }
+
+    pub fn transfer_payout(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        recipient: &Address,
+        amount: i128,
+        cycle_number: u32,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            recipient.into_val(&self.env),
+            amount.into_val(&self.env),
+            cycle_number.into_val(&self.env),
+        ];
+        self.invoke("transfer_payout", args)
+    }
+
+    pub fn delete_group(&mut self, group_id: u64, caller: &Address) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+        ];
+        self.invoke("delete_group", args)
+    }
+
+    pub fn get_total_groups(&mut self) -> Result<u64> {
+        self.invoke("get_total_groups", Vec::new(&self.env))
+    }
+
+    pub fn list_groups(
+        &mut self,
+        cursor: u64,
+        limit: u32,
+        status_filter: &Option<GroupStatus>,
+    ) -> Result<Vec<Group>> {
+        let args = vec![
+            &self.env,
+            cursor.into_val(&self.env),
+            limit.into_val(&self.env),
+            status_filter.into_val(&self.env),
+        ];
+        self.invoke("list_groups", args)
+    }
+
+    pub fn get_total_groups_created(&mut self) -> Result<u64> {
+        self.invoke("get_total_groups_created", Vec::new(&self.env))
+    }
+
+    pub fn get_contract_balance(&mut self) -> Result<i128> {
+        self.invoke("get_contract_balance", Vec::new(&self.env))
+    }
+
+    pub fn get_member_total_contributions(
+        &mut self,
+        group_id: u64,
+        member: &Address,
+    ) -> Result<i128> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("get_member_total_contributions", args)
+    }
+
+    pub fn get_member_contribution_history(
+        &mut self,
+        group_id: u64,
+        member: &Address,
+        start_cycle: u32,
+        limit: u32,
+    ) -> Result<Vec<ContributionRecord>> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+            start_cycle.into_val(&self.env),
+            limit.into_val(&self.env),
+        ];
+        self.invoke("get_member_contribution_history", args)
+    }
+
+    pub fn get_cycle_contributions(
+        &mut self,
+        group_id: u64,
+        cycle_number: u32,
+    ) -> Result<Vec<ContributionRecord>> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            cycle_number.into_val(&self.env),
+        ];
+        self.invoke("get_cycle_contributions", args)
+    }
+
+    pub fn is_cycle_complete(&mut self, group_id: u64, cycle_number: u32) -> Result<bool> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            cycle_number.into_val(&self.env),
+        ];
+        self.invoke("is_cycle_complete", args)
+    }
+
+    pub fn get_missed_contributions(
+        &mut self,
+        group_id: u64,
+        cycle_number: u32,
+    ) -> Result<Vec<Address>> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            cycle_number.into_val(&self.env),
+        ];
+        self.invoke("get_missed_contributions", args)
+    }
+
+    pub fn get_contribution_deadline(&mut self, group_id: u64, cycle_number: u32) -> Result<u64> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            cycle_number.into_val(&self.env),
+        ];
+        self.invoke("get_contribution_deadline", args)
+    }
+
+    pub fn get_next_payout_cycle(&mut self, group_id: u64) -> Result<u64> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_next_payout_cycle", args)
+    }
+
+    pub fn join_group(&mut self, group_id: u64, member: &Address) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("join_group", args)
+    }
+
+    pub fn join_with_invite(
+        &mut self,
+        group_id: u64,
+        member: &Address,
+        code: &Bytes,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+            code.into_val(&self.env),
+        ];
+        self.invoke("join_with_invite", args)
+    }
+
+    pub fn set_join_policy(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        policy: &JoinPolicy,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            policy.into_val(&self.env),
+        ];
+        self.invoke("set_join_policy", args)
+    }
+
+    pub fn get_join_policy(&mut self, group_id: u64) -> Result<JoinPolicy> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_join_policy", args)
+    }
+
+    pub fn add_to_allowlist(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        address: &Address,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            address.into_val(&self.env),
+        ];
+        self.invoke("add_to_allowlist", args)
+    }
+
+    pub fn remove_from_allowlist(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        address: &Address,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            address.into_val(&self.env),
+        ];
+        self.invoke("remove_from_allowlist", args)
+    }
+
+    pub fn is_allowlisted(&mut self, group_id: u64, address: &Address) -> Result<bool> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            address.into_val(&self.env),
+        ];
+        self.invoke("is_allowlisted", args)
+    }
+
+    pub fn create_invite(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        code_hash: &BytesN<32>,
+        expires_at: u64,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            code_hash.into_val(&self.env),
+            expires_at.into_val(&self.env),
+        ];
+        self.invoke("create_invite", args)
+    }
+
+    pub fn revoke_invite(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        code_hash: &BytesN<32>,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            code_hash.into_val(&self.env),
+        ];
+        self.invoke("revoke_invite", args)
+    }
+
+    pub fn get_invite(&mut self, group_id: u64, code_hash: &BytesN<32>) -> Result<Option<Invite>> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            code_hash.into_val(&self.env),
+        ];
+        self.invoke("get_invite", args)
+    }
+
+    pub fn request_to_join(
+        &mut self,
+        group_id: u64,
+        member: &Address,
+        message_hash: &BytesN<32>,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+            message_hash.into_val(&self.env),
+        ];
+        self.invoke("request_to_join", args)
+    }
+
+    pub fn approve_join(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        member: &Address,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("approve_join", args)
+    }
+
+    pub fn reject_join(&mut self, group_id: u64, caller: &Address, member: &Address) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("reject_join", args)
+    }
+
+    pub fn get_join_requests(&mut self, group_id: u64) -> Result<Vec<JoinRequest>> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_join_requests", args)
+    }
+
+    pub fn set_activation_policy(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        policy: &ActivationPolicy,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            policy.into_val(&self.env),
+        ];
+        self.invoke("set_activation_policy", args)
+    }
+
+    pub fn set_cycle_schedule(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        schedule: &CycleSchedule,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            schedule.into_val(&self.env),
+        ];
+        self.invoke("set_cycle_schedule", args)
+    }
+
+    pub fn get_activation_policy(&mut self, group_id: u64) -> Result<ActivationPolicy> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_activation_policy", args)
+    }
+
+    pub fn process_activation(&mut self, group_id: u64) -> Result<GroupStatus> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("process_activation", args)
+    }
+
+    pub fn emergency_withdraw(&mut self, group_id: u64, member: &Address) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("emergency_withdraw", args)
+    }
+
+    pub fn get_group_members(
+        &mut self,
+        group_id: u64,
+        offset: u32,
+        limit: u32,
+    ) -> Result<Vec<Address>> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            offset.into_val(&self.env),
+            limit.into_val(&self.env),
+        ];
+        self.invoke("get_group_members", args)
+    }
+
+    pub fn activate_group(&mut self, group_id: u64, caller: &Address) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+        ];
+        self.invoke("activate_group", args)
+    }
+
+    pub fn grant_role(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        address: &Address,
+        role: &GroupRole,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            address.into_val(&self.env),
+            role.into_val(&self.env),
+        ];
+        self.invoke("grant_role", args)
+    }
+
+    pub fn revoke_role(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        address: &Address,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            address.into_val(&self.env),
+        ];
+        self.invoke("revoke_role", args)
+    }
+
+    pub fn transfer_ownership(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        new_owner: &Address,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            new_owner.into_val(&self.env),
+        ];
+        self.invoke("transfer_ownership", args)
+    }
+
+    pub fn get_role(&mut self, group_id: u64, address: &Address) -> Result<Option<GroupRole>> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            address.into_val(&self.env),
+        ];
+        self.invoke("get_role", args)
+    }
+
+    pub fn remove_member(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        member: &Address,
+    ) -> Result<MemberSettlement> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("remove_member", args)
+    }
+
+    pub fn replace_member(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        old_member: &Address,
+        new_member: &Address,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            old_member.into_val(&self.env),
+            new_member.into_val(&self.env),
+        ];
+        self.invoke("replace_member", args)
+    }
+
+    pub fn get_member_settlement(
+        &mut self,
+        group_id: u64,
+        member: &Address,
+    ) -> Result<Option<MemberSettlement>> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            member.into_val(&self.env),
+        ];
+        self.invoke("get_member_settlement", args)
+    }
+
+    pub fn rotate_member_address(
+        &mut self,
+        group_id: u64,
+        old_address: &Address,
+        new_address: &Address,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            old_address.into_val(&self.env),
+            new_address.into_val(&self.env),
+        ];
+        self.invoke("rotate_member_address", args)
+    }
+
+    pub fn recover_member_address(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        old_address: &Address,
+        new_address: &Address,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            old_address.into_val(&self.env),
+            new_address.into_val(&self.env),
+        ];
+        self.invoke("recover_member_address", args)
+    }
+
+    pub fn set_governance_config(
+        &mut self,
+        group_id: u64,
+        caller: &Address,
+        config: &GovernanceConfig,
+    ) -> Result<()> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            caller.into_val(&self.env),
+            config.into_val(&self.env),
+        ];
+        self.invoke("set_governance_config", args)
+    }
+
+    pub fn get_governance_config(&mut self, group_id: u64) -> Result<GovernanceConfig> {
+        let args = vec![&self.env, group_id.into_val(&self.env)];
+        self.invoke("get_governance_config", args)
+    }
+
+    pub fn create_proposal(
+        &mut self,
+        group_id: u64,
+        proposer: &Address,
+        action: &ProposalAction,
+    ) -> Result<u32> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            proposer.into_val(&self.env),
+            action.into_val(&self.env),
+        ];
+        self.invoke("create_proposal", args)
+    }
+
+    pub fn vote(
+        &mut self,
+        group_id: u64,
+        proposal_id: u32,
+        voter: &Address,
+        support: bool,
+    ) -> Result<ProposalStatus> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            proposal_id.into_val(&self.env),
+            voter.into_val(&self.env),
+            support.into_val(&self.env),
+        ];
+        self.invoke("vote", args)
+    }
+
+    pub fn finalize_proposal(&mut self, group_id: u64, proposal_id: u32) -> Result<ProposalStatus> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            proposal_id.into_val(&self.env),
+        ];
+        self.invoke("finalize_proposal", args)
+    }
+
+    pub fn get_proposal(&mut self, group_id: u64, proposal_id: u32) -> Result<Proposal> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            proposal_id.into_val(&self.env),
+        ];
+        self.invoke("get_proposal", args)
+    }
+
+    pub fn get_vote(
+        &mut self,
+        group_id: u64,
+        proposal_id: u32,
+        voter: &Address,
+    ) -> Result<Option<bool>> {
+        let args = vec![
+            &self.env,
+            group_id.into_val(&self.env),
+            proposal_id.into_val(&self.env),
+            voter.into_val(&self.env),
+        ];
+        self.invoke("get_vote", args)
+    }
+}
@@ -0,0 +1,106 @@
+use std::fmt;
+
+use stellar_save::{ErrorCategory, StellarSaveError};
+
+use crate::backend::Failure;
+
+/// Errors returned by [`Client`](crate::Client) methods.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ClientError {
+    /// The contract rejected the call.
+    Contract(StellarSaveError),
+
+    /// The contract returned an error code this client doesn't know, e.g.
+    /// one added by a newer contract version.
+    UnknownContractError(u32),
+
+    /// The host aborted the call.
+    Host(String),
+
+    /// The call never reached the contract.
+    Transport(String),
+
+    /// A value couldn't be converted to or from XDR.
+    Conversion(String),
+}
+
+impl ClientError {
+    /// Returns the contract error, if the contract rejected the call.
+    pub fn contract_error(&self) -> Option<StellarSaveError> {
+        match self {
+            ClientError::Contract(err) => Some(*err),
+            _ => None,
+        }
+    }
+
+    /// Returns the contract error code (see `StellarSaveError::code`).
+    pub fn code(&self) -> Option<u32> {
+        match self {
+            ClientError::Contract(err) => Some(err.code()),
+            ClientError::UnknownContractError(code) => Some(*code),
+            _ => None,
+        }
+    }
+
+    /// Returns the contract error category (see `StellarSaveError::category`).
+    pub fn category(&self) -> Option<ErrorCategory> {
+        self.contract_error().map(|err| err.category())
+    }
+}
+
+impl From<Failure> for ClientError {
+    fn from(failure: Failure) -> Self {
+        match failure {
+            Failure::Contract(code) => {
+                let err = soroban_sdk::Error::from_contract_error(code);
+                match StellarSaveError::try_from(err) {
+                    Ok(err) => ClientError::Contract(err),
+                    Err(_) => ClientError::UnknownContractError(code),
+                }
+            }
+            Failure::Host(reason) => ClientError::Host(reason),
+            Failure::Transport(reason) => ClientError::Transport(reason),
+        }
+    }
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::Contract(err) => {
+                write!(f, "contract error {}: {}", err.code(), err.message())
+            }
+            ClientError::UnknownContractError(code) => {
+                write!(f, "unknown contract error {}", code)
+            }
+            ClientError::Host(reason) => write!(f, "host error: {}", reason),
+            ClientError::Transport(reason) => write!(f, "transport error: {}", reason),
+            ClientError::Conversion(reason) => write!(f, "conversion error: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+pub type Result<T> = std::result::Result<T, ClientError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contract_failures_decode_to_typed_errors() {
+        let err = ClientError::from(Failure::Contract(StellarSaveError::GroupFull.code()));
+        assert_eq!(err.contract_error(), Some(StellarSaveError::GroupFull));
+        assert_eq!(err.code(), Some(StellarSaveError::GroupFull.code()));
+        assert_eq!(err.category(), Some(StellarSaveError::GroupFull.category()));
+
+        let unknown = ClientError::from(Failure::Contract(65_000));
+        assert_eq!(unknown, ClientError::UnknownContractError(65_000));
+        assert_eq!(unknown.code(), Some(65_000));
+        assert_eq!(unknown.category(), None);
+
+        let transport = ClientError::from(Failure::Transport("timeout".into()));
+        assert_eq!(transport.code(), None);
+    }
+}
@@ -0,0 +1,28 @@
+//! Typed Rust client for the Stellar-Save contract.
+//!
+//! [`Client`] wraps every contract entry point in a typed method, decodes
+//! contract error codes into [`StellarSaveError`] and can dry-run calls
+//! through [`Client::simulation`]. Calls go through a [`Backend`]: services
+//! implement it over their RPC transport, and the `mock` feature provides
+//! [`mock::MockBackend`] for tests that shouldn't need a network.
+
+pub mod backend;
+pub mod client;
+mod entry_points;
+pub mod error;
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
+
+pub use backend::{Backend, Cost, Failure, Invocation, Simulation};
+pub use client::Client;
+pub use error::{ClientError, Result};
+
+pub use soroban_sdk;
+pub use stellar_save::{
+    ActivationPolicy, AssignmentMode, ContractConfig, ContributionRecord, CycleSchedule,
+    ErrorCategory, GovernanceConfig, Group, GroupRole, GroupStatus, GroupVault, Invite, JoinPolicy,
+    JoinRequest, MemberProfile, MemberSettlement, PayoutDestination, PayoutRecord,
+    PayoutScheduleEntry, PayoutSlot, PoolInfo, Prepayment, Proposal, ProposalAction,
+    ProposalStatus, RenewalPolicy, RoundSummary, SavingsClubConfig, SavingsWithdrawal,
+    StellarSaveError, VaultPosition,
+};
@@ -0,0 +1,133 @@
+//! In-process backend over the soroban test environment.
+//!
+//! Runs the real contract natively, so code using the client can be unit
+//! tested without a network. Auth is mocked for every call.
+
+use soroban_sdk::testutils::{EnvTestConfig, Snapshot};
+use soroban_sdk::xdr::{LedgerKey, LedgerKeyContractData, ScVal};
+use soroban_sdk::{Address, Env, InvokeError, Symbol, TryFromVal, Val, Vec};
+use stellar_save::StellarSaveContract;
+
+use crate::backend::{Backend, Cost, Failure, Invocation};
+use crate::client::Client;
+
+/// Backend that runs calls against a contract registered in an [`Env`].
+pub struct MockBackend {
+    env: Env,
+    contract_id: Address,
+}
+
+impl MockBackend {
+    /// Registers a fresh contract in a new environment.
+    pub fn new() -> Self {
+        let env = Env::new_with_config(EnvTestConfig {
+            capture_snapshot_at_drop: false,
+        });
+        env.mock_all_auths();
+        let contract_id = env.register(StellarSaveContract, ());
+        Self { env, contract_id }
+    }
+
+    /// Registers the contract at `contract_id` in an existing environment,
+    /// e.g. one restored with [`env_from_snapshot`]. Contract data already
+    /// in the ledger is kept.
+    pub fn with_env(env: Env, contract_id: &Address) -> Self {
+        env.mock_all_auths();
+        env.register_at(contract_id, StellarSaveContract, ());
+        Self {
+            contract_id: contract_id.clone(),
+            env,
+        }
+    }
+
+    /// Returns a client over this backend sharing its environment, so
+    /// addresses generated from `client.env()` work directly.
+    pub fn into_client(self) -> Client<Self> {
+        Client::with_env(self.env.clone(), self)
+    }
+
+    /// Returns the environment, e.g. to generate addresses or move the ledger.
+    pub fn env(&self) -> &Env {
+        &self.env
+    }
+
+    /// Returns the address of the registered contract.
+    pub fn contract_id(&self) -> &Address {
+        &self.contract_id
+    }
+
+    fn call(env: &Env, contract_id: &Address, call: &Invocation) -> Result<ScVal, Failure> {
+        let mut args = Vec::<Val>::new(env);
+        for arg in &call.args {
+            args.push_back(Val::try_from_val(env, arg).map_err(host_error)?);
+        }
+
+        let function = Symbol::new(env, &call.function);
+        match env.try_invoke_contract::<Val, InvokeError>(contract_id, &function, args) {
+            Ok(Ok(value)) => ScVal::try_from_val(env, &value).map_err(host_error),
+            Ok(Err(err)) => Err(host_error(err)),
+            Err(Ok(InvokeError::Contract(code))) => Err(Failure::Contract(code)),
+            Err(Ok(InvokeError::Abort)) | Err(Err(_)) => {
+                Err(Failure::Host(format!("{} aborted", call.function)))
+            }
+        }
+    }
+}
+
+/// Restores an environment from a snapshot, for use with [`MockBackend::with_env`].
+///
+/// Mocked auth draws its nonces from a generator that restarts with every
+/// environment, so the nonces already recorded in the ledger are dropped;
+/// otherwise the first authorized call would collide with one of them.
+pub fn env_from_snapshot(mut snapshot: Snapshot) -> Env {
+    snapshot.ledger.ledger_entries.retain(|(key, _)| {
+        !matches!(
+            key.as_ref(),
+            LedgerKey::ContractData(LedgerKeyContractData {
+                key: ScVal::LedgerKeyNonce(_),
+                ..
+            })
+        )
+    });
+    let mut env = Env::from_snapshot(snapshot);
+    env.set_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    env
+}
+
+fn host_error(err: impl core::fmt::Debug) -> Failure {
+    Failure::Host(format!("{:?}", err))
+}
+
+impl Default for MockBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Backend for MockBackend {
+    fn submit(&mut self, call: &Invocation) -> Result<ScVal, Failure> {
+        Self::call(&self.env, &self.contract_id, call)
+    }
+
+    fn simulate(&mut self, call: &Invocation) -> Result<(ScVal, Cost), Failure> {
+        // Run against a copy of the ledger so nothing sticks
+        let env = env_from_snapshot(self.env.to_snapshot());
+
+        // Host objects belong to one environment; move the address over as XDR
+        let id = ScVal::try_from_val(&self.env, &self.contract_id.to_val()).map_err(host_error)?;
+        let id = Val::try_from_val(&env, &id).map_err(host_error)?;
+        let contract_id = Address::try_from_val(&env, &id).map_err(host_error)?;
+        let copy = Self::with_env(env, &contract_id);
+
+        copy.env.cost_estimate().budget().reset_default();
+        let value = Self::call(&copy.env, &copy.contract_id, call)?;
+        let budget = copy.env.cost_estimate().budget();
+        let cost = Cost {
+            cpu_instructions: budget.cpu_instruction_cost(),
+            memory_bytes: budget.memory_bytes_cost(),
+        };
+        Ok((value, cost))
+    }
+}
@@ -0,0 +1,370 @@
+use stellar_xdr::curr::{Limits, ReadXdr, ScMap, ScVal};
+
+use crate::error::{IndexerError, Result};
+use crate::source::RawEvent;
+
+/// First topic of every Stellar-Save event (`EVENT_CONTRACT_NAME`).
+pub const EVENT_CONTRACT_NAME: &str = "stellar_save";
+
+/// Newest event schema this indexer understands (`EVENT_SCHEMA_VERSION`).
+pub const SUPPORTED_SCHEMA_VERSION: u32 = 1;
+
+/// An event that changes indexed state, decoded from its `events.rs` struct.
+///
+/// Addresses are kept as strkeys and amounts in stroops. Events the indexer
+/// doesn't materialize (settings, governance, invites...) aren't decoded.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ContractEvent {
+    GroupCreated {
+        group_id: u64,
+        creator: String,
+        contribution_amount: i128,
+        cycle_duration: u64,
+        max_members: u32,
+        created_at: u64,
+    },
+    GroupActivated {
+        group_id: u64,
+        member_count: u32,
+        activated_at: u64,
+    },
+    GroupStatusChanged {
+        group_id: u64,
+        old_status: u32,
+        new_status: u32,
+        changed_at: u64,
+    },
+    GroupCompleted {
+        group_id: u64,
+        total_cycles: u32,
+        total_distributed: i128,
+        completed_at: u64,
+    },
+    GroupCancelled {
+        group_id: u64,
+        cancelled_at: u64,
+    },
+    GroupDeleted {
+        group_id: u64,
+        deleted_at: u64,
+    },
+    CycleAdvanced {
+        group_id: u64,
+        cycle: u32,
+        advanced_at: u64,
+    },
+    MemberJoined {
+        group_id: u64,
+        member: String,
+        member_count: u32,
+        joined_at: u64,
+    },
+    MemberLeft {
+        group_id: u64,
+        member: String,
+        member_count: u32,
+        left_at: u64,
+    },
+    MemberRemoved {
+        group_id: u64,
+        member: String,
+        member_count: u32,
+        removed_at: u64,
+    },
+    MemberReplaced {
+        group_id: u64,
+        old_member: String,
+        new_member: String,
+        replaced_at: u64,
+    },
+    MemberAddressRotated {
+        group_id: u64,
+        old_address: String,
+        new_address: String,
+        rotated_at: u64,
+    },
+    ContributionMade {
+        group_id: u64,
+        contributor: String,
+        payer: String,
+        amount: i128,
+        cycle: u32,
+        contributed_at: u64,
+    },
+    PayoutExecuted {
+        group_id: u64,
+        recipient: String,
+        amount: i128,
+        cycle: u32,
+        executed_at: u64,
+    },
+}
+
+/// Decodes a raw event.
+///
+/// # Returns
+/// * `Ok(Some(event))` - A Stellar-Save event the indexer materializes
+/// * `Ok(None)` - Another contract's event, or one the indexer ignores
+/// * `Err(IndexerError::UnsupportedVersion)` - Published by a newer schema
+/// * `Err(_)` - Invalid XDR, or fields missing from a known event
+pub fn decode(raw: &RawEvent) -> Result<Option<ContractEvent>> {
+    // 1. Topics are (contract name, event name, schema version, group id)
+    let topics = raw
+        .topic
+        .iter()
+        .map(|t| ScVal::from_xdr_base64(t, Limits::none()))
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    if topics.len() != 4 || symbol(&topics[0]).as_deref() != Some(EVENT_CONTRACT_NAME) {
+        return Ok(None);
+    }
+
+    let name = symbol(&topics[1]).ok_or_else(|| malformed(raw, "event name is not a symbol"))?;
+
+    // 2. Refuse to guess at layouts from a newer schema
+    let version = match &topics[2] {
+        ScVal::U32(v) => *v,
+        _ => return Err(malformed(raw, "schema version is not a u32")),
+    };
+    if version > SUPPORTED_SCHEMA_VERSION {
+        return Err(IndexerError::UnsupportedVersion {
+            event_id: raw.id.clone(),
+            version,
+        });
+    }
+
+    // 3. Read the struct fields by name
+    let fields = Fields {
+        raw,
+        map: match ScVal::from_xdr_base64(&raw.value, Limits::none())? {
+            ScVal::Map(Some(map)) => map,
+            _ => return Err(malformed(raw, "event data is not a struct")),
+        },
+    };
+    let event = match name.as_str() {
+        "group_created" => ContractEvent::GroupCreated {
+            group_id: fields.u64("group_id")?,
+            creator: fields.address("creator")?,
+            contribution_amount: fields.i128("contribution_amount")?,
+            cycle_duration: fields.u64("cycle_duration")?,
+            max_members: fields.u32("max_members")?,
+            created_at: fields.u64("created_at")?,
+        },
+        "group_activated" => ContractEvent::GroupActivated {
+            group_id: fields.u64("group_id")?,
+            member_count: fields.u32("member_count")?,
+            activated_at: fields.u64("activated_at")?,
+        },
+        "group_status_changed" => ContractEvent::GroupStatusChanged {
+            group_id: fields.u64("group_id")?,
+            old_status: fields.u32("old_status")?,
+            new_status: fields.u32("new_status")?,
+            changed_at: fields.u64("changed_at")?,
+        },
+        "group_completed" => ContractEvent::GroupCompleted {
+            group_id: fields.u64("group_id")?,
+            total_cycles: fields.u32("total_cycles")?,
+            total_distributed: fields.i128("total_distributed")?,
+            completed_at: fields.u64("completed_at")?,
+        },
+        "group_cancelled" => ContractEvent::GroupCancelled {
+            group_id: fields.u64("group_id")?,
+            cancelled_at: fields.u64("cancelled_at")?,
+        },
+        "group_deleted" => ContractEvent::GroupDeleted {
+            group_id: fields.u64("group_id")?,
+            deleted_at: fields.u64("deleted_at")?,
+        },
+        "cycle_advanced" => ContractEvent::CycleAdvanced {
+            group_id: fields.u64("group_id")?,
+            cycle: fields.u32("cycle")?,
+            advanced_at: fields.u64("advanced_at")?,
+        },
+        "member_joined" => ContractEvent::MemberJoined {
+            group_id: fields.u64("group_id")?,
+            member: fields.address("member")?,
+            member_count: fields.u32("member_count")?,
+            joined_at: fields.u64("joined_at")?,
+        },
+        "member_left" => ContractEvent::MemberLeft {
+            group_id: fields.u64("group_id")?,
+            member: fields.address("member")?,
+            member_count: fields.u32("member_count")?,
+            left_at: fields.u64("left_at")?,
+        },
+        "member_removed" => ContractEvent::MemberRemoved {
+            group_id: fields.u64("group_id")?,
+            member: fields.address("member")?,
+            member_count: fields.u32("member_count")?,
+            removed_at: fields.u64("removed_at")?,
+        },
+        "member_replaced" => ContractEvent::MemberReplaced {
+            group_id: fields.u64("group_id")?,
+            old_member: fields.address("old_member")?,
+            new_member: fields.address("new_member")?,
+            replaced_at: fields.u64("replaced_at")?,
+        },
+        "member_address_rotated" => ContractEvent::MemberAddressRotated {
+            group_id: fields.u64("group_id")?,
+            old_address: fields.address("old_address")?,
+            new_address: fields.address("new_address")?,
+            rotated_at: fields.u64("rotated_at")?,
+        },
+        "contribution_made" => ContractEvent::ContributionMade {
+            group_id: fields.u64("group_id")?,
+            contributor: fields.address("contributor")?,
+            payer: fields.address("payer")?,
+            amount: fields.i128("amount")?,
+            cycle: fields.u32("cycle")?,
+            contributed_at: fields.u64("contributed_at")?,
+        },
+        "payout_executed" => ContractEvent::PayoutExecuted {
+            group_id: fields.u64("group_id")?,
+            recipient: fields.address("recipient")?,
+            amount: fields.i128("amount")?,
+            cycle: fields.u32("cycle")?,
+            executed_at: fields.u64("executed_at")?,
+        },
+        _ => return Ok(None),
+    };
+    Ok(Some(event))
+}
+
+fn symbol(val: &ScVal) -> Option<String> {
+    match val {
+        ScVal::Symbol(s) => s.to_utf8_string().ok(),
+        _ => None,
+    }
+}
+
+fn malformed(raw: &RawEvent, reason: impl Into<String>) -> IndexerError {
+    IndexerError::Malformed {
+        event_id: raw.id.clone(),
+        reason: reason.into(),
+    }
+}
+
+/// Named fields of a `#[contracttype]` struct, encoded as a symbol-keyed map.
+struct Fields<'a> {
+    raw: &'a RawEvent,
+    map: ScMap,
+}
+
+impl Fields<'_> {
+    fn get(&self, name: &str) -> Result<&ScVal> {
+        self.map
+            .iter()
+            .find(|entry| symbol(&entry.key).as_deref() == Some(name))
+            .map(|entry| &entry.val)
+            .ok_or_else(|| malformed(self.raw, format!("missing field `{}`", name)))
+    }
+
+    fn wrong_type(&self, name: &str) -> IndexerError {
+        malformed(self.raw, format!("field `{}` has the wrong type", name))
+    }
+
+    fn u32(&self, name: &str) -> Result<u32> {
+        match self.get(name)? {
+            ScVal::U32(v) => Ok(*v),
+            _ => Err(self.wrong_type(name)),
+        }
+    }
+
+    fn u64(&self, name: &str) -> Result<u64> {
+        match self.get(name)? {
+            ScVal::U64(v) => Ok(*v),
+            _ => Err(self.wrong_type(name)),
+        }
+    }
+
+    fn i128(&self, name: &str) -> Result<i128> {
+        match self.get(name)? {
+            ScVal::I128(parts) => Ok(((parts.hi as i128) << 64) | parts.lo as i128),
+            _ => Err(self.wrong_type(name)),
+        }
+    }
+
+    fn address(&self, name: &str) -> Result<String> {
+        match self.get(name)? {
+            ScVal::Address(address) => Ok(address.to_string()),
+            _ => Err(self.wrong_type(name)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stellar_xdr::curr::{Int128Parts, ScMapEntry, ScSymbol, WriteXdr};
+
+    fn sym(s: &str) -> ScVal {
+        ScVal::Symbol(ScSymbol(s.try_into().unwrap()))
+    }
+
+    fn raw(name: &str, version: u32, fields: Vec<(&str, ScVal)>) -> RawEvent {
+        let topic = [sym(EVENT_CONTRACT_NAME), sym(name), ScVal::U32(version), ScVal::U64(1)];
+        let map = ScMap(
+            fields
+                .into_iter()
+                .map(|(key, val)| ScMapEntry { key: sym(key), val })
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap(),
+        );
+        RawEvent {
+            id: "1".to_string(),
+            ledger: 1,
+            contract_id: String::new(),
+            topic: topic
+                .iter()
+                .map(|t| t.to_xdr_base64(Limits::none()).unwrap())
+                .collect(),
+            value: ScVal::Map(Some(map)).to_xdr_base64(Limits::none()).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_decode_reads_fields_by_name() {
+        let event = raw(
+            "cycle_advanced",
+            1,
+            vec![
+                ("advanced_at", ScVal::U64(50)),
+                ("cycle", ScVal::U32(2)),
+                ("group_id", ScVal::U64(1)),
+            ],
+        );
+        assert_eq!(
+            decode(&event).unwrap(),
+            Some(ContractEvent::CycleAdvanced {
+                group_id: 1,
+                cycle: 2,
+                advanced_at: 50,
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_negative_i128() {
+        let amount = ScVal::I128(Int128Parts { hi: -1, lo: u64::MAX - 9 });
+        let fields = Fields {
+            raw: &raw("x", 1, vec![]),
+            map: ScMap(vec![ScMapEntry { key: sym("amount"), val: amount }].try_into().unwrap()),
+        };
+        assert_eq!(fields.i128("amount").unwrap(), -10);
+    }
+
+    #[test]
+    fn test_decode_skips_unknown_and_rejects_newer_versions() {
+        let unknown = raw("vote_cast", 1, vec![]);
+        assert_eq!(decode(&unknown).unwrap(), None);
+
+        let newer = raw("cycle_advanced", SUPPORTED_SCHEMA_VERSION + 1, vec![]);
+        assert!(matches!(
+            decode(&newer),
+            Err(IndexerError::UnsupportedVersion { version: 2, .. })
+        ));
+
+        let missing = raw("cycle_advanced", 1, vec![("group_id", ScVal::U64(1))]);
+        assert!(matches!(decode(&missing), Err(IndexerError::Malformed { .. })));
+    }
+}
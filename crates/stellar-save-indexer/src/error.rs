@@ -0,0 +1,78 @@
+use std::fmt;
+
+/// Errors the indexer can hit while reading, decoding or storing events.
+#[derive(Debug)]
+pub enum IndexerError {
+    /// Reading the event source failed.
+    Io(std::io::Error),
+
+    /// A line of the event source is not a valid event record.
+    Json(serde_json::Error),
+
+    /// A topic or value is not valid base64 XDR.
+    Xdr(stellar_xdr::curr::Error),
+
+    /// The SQLite database rejected a statement.
+    Sqlite(rusqlite::Error),
+
+    /// The event is a Stellar-Save event but doesn't match its schema.
+    Malformed { event_id: String, reason: String },
+
+    /// The event was published with a schema version this indexer predates.
+    UnsupportedVersion { event_id: String, version: u32 },
+}
+
+impl fmt::Display for IndexerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IndexerError::Io(err) => write!(f, "failed to read events: {}", err),
+            IndexerError::Json(err) => write!(f, "invalid event record: {}", err),
+            IndexerError::Xdr(err) => write!(f, "invalid event XDR: {}", err),
+            IndexerError::Sqlite(err) => write!(f, "database error: {}", err),
+            IndexerError::Malformed { event_id, reason } => {
+                write!(f, "malformed event {}: {}", event_id, reason)
+            }
+            IndexerError::UnsupportedVersion { event_id, version } => {
+                write!(f, "event {} uses unsupported schema version {}", event_id, version)
+            }
+        }
+    }
+}
+
+impl std::error::Error for IndexerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            IndexerError::Io(err) => Some(err),
+            IndexerError::Json(err) => Some(err),
+            IndexerError::Xdr(err) => Some(err),
+            IndexerError::Sqlite(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for IndexerError {
+    fn from(err: std::io::Error) -> Self {
+        IndexerError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for IndexerError {
+    fn from(err: serde_json::Error) -> Self {
+        IndexerError::Json(err)
+    }
+}
+
+impl From<stellar_xdr::curr::Error> for IndexerError {
+    fn from(err: stellar_xdr::curr::Error) -> Self {
+        IndexerError::Xdr(err)
+    }
+}
+
+impl From<rusqlite::Error> for IndexerError {
+    fn from(err: rusqlite::Error) -> Self {
+        IndexerError::Sqlite(err)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, IndexerError>;
@@ -0,0 +1,75 @@
+//! Off-chain indexer for Stellar-Save.
+//!
+//! Pages through the contract's events, decodes the `events.rs` types and
+//! materializes groups, members, contributions and payouts into SQLite, so
+//! analytics and support tools can query state without hitting RPC. The
+//! database keeps a cursor with the last event applied; syncing again
+//! resumes after it.
+
+pub mod decode;
+pub mod error;
+pub mod source;
+pub mod store;
+
+pub use decode::{decode, ContractEvent};
+pub use error::{IndexerError, Result};
+pub use source::{write_json_lines, EventSource, JsonFileSource, MemorySource, RawEvent};
+pub use store::{ContributionRow, GroupRow, MemberRow, PayoutRow, Store};
+
+/// Events requested per page, matching the RPC `getEvents` default.
+pub const DEFAULT_PAGE_SIZE: usize = 100;
+
+/// Feeds events from a source into a store.
+pub struct Indexer {
+    store: Store,
+    page_size: usize,
+}
+
+impl Indexer {
+    /// Creates an indexer writing to `store`.
+    pub fn new(store: Store) -> Self {
+        Self {
+            store,
+            page_size: DEFAULT_PAGE_SIZE,
+        }
+    }
+
+    /// Sets how many events are requested per page.
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Applies every event the source has after the store's cursor.
+    ///
+    /// Each event is committed together with the cursor, so an interrupted
+    /// sync picks up at the first event it didn't commit.
+    ///
+    /// # Returns
+    /// The number of events applied.
+    pub fn sync(&mut self, source: &mut dyn EventSource) -> Result<usize> {
+        let mut applied = 0;
+        loop {
+            let cursor = self.store.cursor()?;
+            let page = source.fetch(cursor.as_deref(), self.page_size)?;
+            if page.is_empty() {
+                return Ok(applied);
+            }
+            for event in &page {
+                if self.store.apply(event)? {
+                    applied += 1;
+                }
+            }
+        }
+    }
+
+    /// Returns the store for queries.
+    pub fn store(&self) -> &Store {
+        &self.store
+    }
+
+    /// Returns the store, consuming the indexer.
+    pub fn into_store(self) -> Store {
+        self.store
+    }
+}
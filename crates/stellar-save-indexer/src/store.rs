@@ -0,0 +1,515 @@
+use std::path::Path;
+
+use rusqlite::{params, Connection, OptionalExtension, Transaction};
+
+use crate::decode::{decode, ContractEvent};
+use crate::error::{IndexerError, Result};
+use crate::source::RawEvent;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS groups (
+    group_id            INTEGER PRIMARY KEY,
+    creator             TEXT NOT NULL,
+    contribution_amount INTEGER NOT NULL,
+    cycle_duration      INTEGER NOT NULL,
+    max_members         INTEGER NOT NULL,
+    status              INTEGER NOT NULL DEFAULT 0,
+    current_cycle       INTEGER NOT NULL DEFAULT 0,
+    member_count        INTEGER NOT NULL DEFAULT 0,
+    total_paid_out      INTEGER NOT NULL DEFAULT 0,
+    created_at          INTEGER NOT NULL,
+    activated_at        INTEGER,
+    completed_at        INTEGER,
+    deleted_at          INTEGER
+);
+CREATE TABLE IF NOT EXISTS members (
+    group_id          INTEGER NOT NULL,
+    address           TEXT NOT NULL,
+    joined_at         INTEGER NOT NULL,
+    left_at           INTEGER,
+    total_contributed INTEGER NOT NULL DEFAULT 0,
+    total_received    INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (group_id, address)
+);
+CREATE TABLE IF NOT EXISTS contributions (
+    event_id       TEXT PRIMARY KEY,
+    group_id       INTEGER NOT NULL,
+    contributor    TEXT NOT NULL,
+    payer          TEXT NOT NULL,
+    amount         INTEGER NOT NULL,
+    cycle          INTEGER NOT NULL,
+    contributed_at INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS contributions_by_group ON contributions (group_id, cycle);
+CREATE TABLE IF NOT EXISTS payouts (
+    event_id    TEXT PRIMARY KEY,
+    group_id    INTEGER NOT NULL,
+    recipient   TEXT NOT NULL,
+    amount      INTEGER NOT NULL,
+    cycle       INTEGER NOT NULL,
+    executed_at INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS payouts_by_group ON payouts (group_id, cycle);
+CREATE TABLE IF NOT EXISTS cursor (
+    id       INTEGER PRIMARY KEY CHECK (id = 0),
+    event_id TEXT NOT NULL
+);
+";
+
+/// A group as rebuilt from its events.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupRow {
+    pub group_id: u64,
+    pub creator: String,
+    pub contribution_amount: i64,
+    pub cycle_duration: u64,
+    pub max_members: u32,
+    /// Status code as in events
+    /// (0 = Pending, 1 = Active, 2 = Paused, 3 = Completed, 4 = Cancelled).
+    pub status: u32,
+    pub current_cycle: u32,
+    pub member_count: u32,
+    pub total_paid_out: i64,
+    pub created_at: u64,
+    pub activated_at: Option<u64>,
+    pub completed_at: Option<u64>,
+    pub deleted_at: Option<u64>,
+}
+
+/// A member of a group. `left_at` is set once they leave, are removed or
+/// are replaced.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberRow {
+    pub group_id: u64,
+    pub address: String,
+    pub joined_at: u64,
+    pub left_at: Option<u64>,
+    pub total_contributed: i64,
+    pub total_received: i64,
+}
+
+/// A contribution credited to a member.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributionRow {
+    pub event_id: String,
+    pub group_id: u64,
+    pub contributor: String,
+    pub payer: String,
+    pub amount: i64,
+    pub cycle: u32,
+    pub contributed_at: u64,
+}
+
+/// A payout to a cycle's recipient.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutRow {
+    pub event_id: String,
+    pub group_id: u64,
+    pub recipient: String,
+    pub amount: i64,
+    pub cycle: u32,
+    pub executed_at: u64,
+}
+
+/// SQLite database holding the materialized state and the replay cursor.
+pub struct Store {
+    conn: Connection,
+}
+
+impl Store {
+    /// Opens (or creates) the database at `path`.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        Self::init(Connection::open(path)?)
+    }
+
+    /// Opens a throwaway in-memory database.
+    pub fn open_in_memory() -> Result<Self> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    /// Returns the id of the last event applied, if any.
+    pub fn cursor(&self) -> Result<Option<String>> {
+        Ok(self
+            .conn
+            .query_row("SELECT event_id FROM cursor WHERE id = 0", [], |row| row.get(0))
+            .optional()?)
+    }
+
+    /// Applies one event and advances the cursor past it, atomically.
+    ///
+    /// Events at or before the cursor were already applied and are skipped,
+    /// so replaying a source from the start is harmless. Events the indexer
+    /// doesn't materialize still advance the cursor.
+    ///
+    /// # Returns
+    /// * `Ok(true)` - The event was applied
+    /// * `Ok(false)` - The event was at or before the cursor
+    pub fn apply(&mut self, raw: &RawEvent) -> Result<bool> {
+        if let Some(cursor) = self.cursor()? {
+            if raw.id <= cursor {
+                return Ok(false);
+            }
+        }
+
+        let event = decode(raw)?;
+        let tx = self.conn.transaction()?;
+        if let Some(event) = event {
+            apply_event(&tx, raw, event)?;
+        }
+        tx.execute(
+            "INSERT INTO cursor (id, event_id) VALUES (0, ?1)
+             ON CONFLICT (id) DO UPDATE SET event_id = excluded.event_id",
+            params![raw.id],
+        )?;
+        tx.commit()?;
+        Ok(true)
+    }
+
+    /// Returns a group by ID.
+    pub fn group(&self, group_id: u64) -> Result<Option<GroupRow>> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT * FROM groups WHERE group_id = ?1",
+                params![group_id as i64],
+                group_row,
+            )
+            .optional()?)
+    }
+
+    /// Returns every group, oldest first.
+    pub fn groups(&self) -> Result<Vec<GroupRow>> {
+        let mut stmt = self.conn.prepare("SELECT * FROM groups ORDER BY group_id")?;
+        let rows = stmt.query_map([], group_row)?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// Returns a group's current and former members in join order.
+    pub fn members(&self, group_id: u64) -> Result<Vec<MemberRow>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT group_id, address, joined_at, left_at, total_contributed, total_received
+             FROM members WHERE group_id = ?1 ORDER BY joined_at, rowid",
+        )?;
+        let rows = stmt.query_map(params![group_id as i64], |row| {
+            Ok(MemberRow {
+                group_id: row.get::<_, i64>(0)? as u64,
+                address: row.get(1)?,
+                joined_at: row.get::<_, i64>(2)? as u64,
+                left_at: row.get::<_, Option<i64>>(3)?.map(|t| t as u64),
+                total_contributed: row.get(4)?,
+                total_received: row.get(5)?,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// Returns a group's contributions in the order they were made.
+    pub fn contributions(&self, group_id: u64) -> Result<Vec<ContributionRow>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT event_id, group_id, contributor, payer, amount, cycle, contributed_at
+             FROM contributions WHERE group_id = ?1 ORDER BY event_id",
+        )?;
+        let rows = stmt.query_map(params![group_id as i64], |row| {
+            Ok(ContributionRow {
+                event_id: row.get(0)?,
+                group_id: row.get::<_, i64>(1)? as u64,
+                contributor: row.get(2)?,
+                payer: row.get(3)?,
+                amount: row.get(4)?,
+                cycle: row.get(5)?,
+                contributed_at: row.get::<_, i64>(6)? as u64,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// Returns a group's payouts in the order they were executed.
+    pub fn payouts(&self, group_id: u64) -> Result<Vec<PayoutRow>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT event_id, group_id, recipient, amount, cycle, executed_at
+             FROM payouts WHERE group_id = ?1 ORDER BY event_id",
+        )?;
+        let rows = stmt.query_map(params![group_id as i64], |row| {
+            Ok(PayoutRow {
+                event_id: row.get(0)?,
+                group_id: row.get::<_, i64>(1)? as u64,
+                recipient: row.get(2)?,
+                amount: row.get(3)?,
+                cycle: row.get(4)?,
+                executed_at: row.get::<_, i64>(5)? as u64,
+            })
+        })?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// Returns the underlying connection for ad-hoc analytics queries.
+    pub fn connection(&self) -> &Connection {
+        &self.conn
+    }
+}
+
+fn group_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<GroupRow> {
+    Ok(GroupRow {
+        group_id: row.get::<_, i64>("group_id")? as u64,
+        creator: row.get("creator")?,
+        contribution_amount: row.get("contribution_amount")?,
+        cycle_duration: row.get::<_, i64>("cycle_duration")? as u64,
+        max_members: row.get("max_members")?,
+        status: row.get("status")?,
+        current_cycle: row.get("current_cycle")?,
+        member_count: row.get("member_count")?,
+        total_paid_out: row.get("total_paid_out")?,
+        created_at: row.get::<_, i64>("created_at")? as u64,
+        activated_at: row.get::<_, Option<i64>>("activated_at")?.map(|t| t as u64),
+        completed_at: row.get::<_, Option<i64>>("completed_at")?.map(|t| t as u64),
+        deleted_at: row.get::<_, Option<i64>>("deleted_at")?.map(|t| t as u64),
+    })
+}
+
+/// SQLite integers are 64-bit; stroop amounts on a real network fit easily.
+fn amount(raw: &RawEvent, value: i128) -> Result<i64> {
+    i64::try_from(value).map_err(|_| IndexerError::Malformed {
+        event_id: raw.id.clone(),
+        reason: format!("amount {} does not fit in 64 bits", value),
+    })
+}
+
+fn apply_event(tx: &Transaction<'_>, raw: &RawEvent, event: ContractEvent) -> Result<()> {
+    match event {
+        ContractEvent::GroupCreated {
+            group_id,
+            creator,
+            contribution_amount,
+            cycle_duration,
+            max_members,
+            created_at,
+        } => {
+            tx.execute(
+                "INSERT OR REPLACE INTO groups
+                 (group_id, creator, contribution_amount, cycle_duration, max_members, created_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    group_id as i64,
+                    creator,
+                    amount(raw, contribution_amount)?,
+                    cycle_duration as i64,
+                    max_members,
+                    created_at as i64
+                ],
+            )?;
+        }
+        ContractEvent::GroupActivated {
+            group_id,
+            member_count,
+            activated_at,
+        } => {
+            tx.execute(
+                "UPDATE groups SET status = 1, member_count = ?2, activated_at = ?3
+                 WHERE group_id = ?1",
+                params![group_id as i64, member_count, activated_at as i64],
+            )?;
+        }
+        ContractEvent::GroupStatusChanged {
+            group_id,
+            new_status,
+            ..
+        } => {
+            tx.execute(
+                "UPDATE groups SET status = ?2 WHERE group_id = ?1",
+                params![group_id as i64, new_status],
+            )?;
+        }
+        ContractEvent::GroupCompleted {
+            group_id,
+            completed_at,
+            ..
+        } => {
+            tx.execute(
+                "UPDATE groups SET status = 3, completed_at = ?2 WHERE group_id = ?1",
+                params![group_id as i64, completed_at as i64],
+            )?;
+        }
+        ContractEvent::GroupCancelled { group_id, .. } => {
+            tx.execute(
+                "UPDATE groups SET status = 4 WHERE group_id = ?1",
+                params![group_id as i64],
+            )?;
+        }
+        ContractEvent::GroupDeleted {
+            group_id,
+            deleted_at,
+        } => {
+            tx.execute(
+                "UPDATE groups SET deleted_at = ?2 WHERE group_id = ?1",
+                params![group_id as i64, deleted_at as i64],
+            )?;
+        }
+        ContractEvent::CycleAdvanced {
+            group_id, cycle, ..
+        } => {
+            tx.execute(
+                "UPDATE groups SET current_cycle = ?2 WHERE group_id = ?1",
+                params![group_id as i64, cycle],
+            )?;
+        }
+        ContractEvent::MemberJoined {
+            group_id,
+            member,
+            member_count,
+            joined_at,
+        } => {
+            join(tx, group_id, &member, joined_at)?;
+            set_member_count(tx, group_id, member_count)?;
+        }
+        ContractEvent::MemberLeft {
+            group_id,
+            member,
+            member_count,
+            left_at,
+        }
+        | ContractEvent::MemberRemoved {
+            group_id,
+            member,
+            member_count,
+            removed_at: left_at,
+        } => {
+            leave(tx, group_id, &member, left_at)?;
+            set_member_count(tx, group_id, member_count)?;
+        }
+        ContractEvent::MemberReplaced {
+            group_id,
+            old_member,
+            new_member,
+            replaced_at,
+        } => {
+            leave(tx, group_id, &old_member, replaced_at)?;
+            join(tx, group_id, &new_member, replaced_at)?;
+        }
+        ContractEvent::MemberAddressRotated {
+            group_id,
+            old_address,
+            new_address,
+            ..
+        } => {
+            tx.execute(
+                "UPDATE members SET address = ?3 WHERE group_id = ?1 AND address = ?2",
+                params![group_id as i64, old_address, new_address],
+            )?;
+        }
+        ContractEvent::ContributionMade {
+            group_id,
+            contributor,
+            payer,
+            amount: value,
+            cycle,
+            contributed_at,
+        } => {
+            let value = amount(raw, value)?;
+            tx.execute(
+                "INSERT INTO contributions
+                 (event_id, group_id, contributor, payer, amount, cycle, contributed_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![
+                    raw.id,
+                    group_id as i64,
+                    contributor,
+                    payer,
+                    value,
+                    cycle,
+                    contributed_at as i64
+                ],
+            )?;
+            tx.execute(
+                "UPDATE members SET total_contributed = total_contributed + ?3
+                 WHERE group_id = ?1 AND address = ?2",
+                params![group_id as i64, contributor, value],
+            )?;
+        }
+        ContractEvent::PayoutExecuted {
+            group_id,
+            recipient,
+            amount: value,
+            cycle,
+            executed_at,
+        } => {
+            let value = amount(raw, value)?;
+            tx.execute(
+                "INSERT INTO payouts (event_id, group_id, recipient, amount, cycle, executed_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![raw.id, group_id as i64, recipient, value, cycle, executed_at as i64],
+            )?;
+            tx.execute(
+                "UPDATE members SET total_received = total_received + ?3
+                 WHERE group_id = ?1 AND address = ?2",
+                params![group_id as i64, recipient, value],
+            )?;
+            tx.execute(
+                "UPDATE groups SET total_paid_out = total_paid_out + ?2 WHERE group_id = ?1",
+                params![group_id as i64, value],
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Adds a member, or brings back one who left and rejoined.
+fn join(tx: &Transaction<'_>, group_id: u64, member: &str, joined_at: u64) -> Result<()> {
+    tx.execute(
+        "INSERT INTO members (group_id, address, joined_at) VALUES (?1, ?2, ?3)
+         ON CONFLICT (group_id, address)
+         DO UPDATE SET joined_at = excluded.joined_at, left_at = NULL",
+        params![group_id as i64, member, joined_at as i64],
+    )?;
+    Ok(())
+}
+
+fn leave(tx: &Transaction<'_>, group_id: u64, member: &str, left_at: u64) -> Result<()> {
+    tx.execute(
+        "UPDATE members SET left_at = ?3 WHERE group_id = ?1 AND address = ?2",
+        params![group_id as i64, member, left_at as i64],
+    )?;
+    Ok(())
+}
+
+fn set_member_count(tx: &Transaction<'_>, group_id: u64, member_count: u32) -> Result<()> {
+    tx.execute(
+        "UPDATE groups SET member_count = ?2 WHERE group_id = ?1",
+        params![group_id as i64, member_count],
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn foreign_event(id: &str) -> RawEvent {
+        // Another contract's event: a lone `U32(7)` topic
+        RawEvent {
+            id: id.to_string(),
+            ledger: 1,
+            contract_id: String::new(),
+            topic: vec!["AAAAAwAAAAc=".to_string()],
+            value: "AAAAAQ==".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_apply_advances_cursor_and_skips_replays() {
+        let mut store = Store::open_in_memory().unwrap();
+        assert_eq!(store.cursor().unwrap(), None);
+
+        assert!(store.apply(&foreign_event("2")).unwrap());
+        assert_eq!(store.cursor().unwrap(), Some("2".to_string()));
+        assert!(!store.apply(&foreign_event("1")).unwrap());
+        assert!(!store.apply(&foreign_event("2")).unwrap());
+        assert!(store.apply(&foreign_event("3")).unwrap());
+        assert_eq!(store.cursor().unwrap(), Some("3".to_string()));
+        assert!(store.groups().unwrap().is_empty());
+    }
+}
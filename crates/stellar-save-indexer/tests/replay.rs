@@ -0,0 +1,159 @@
+//! Runs the contract in the soroban test environment, captures the events it
+//! emits and checks the indexer rebuilds the same state from them.
+
+use soroban_sdk::testutils::{Address as _, Events as _};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::xdr::{Limits, ScVal, WriteXdr};
+use soroban_sdk::{Address, Env, TryFromVal};
+use stellar_save::{GroupStatus, StellarSaveContract, StellarSaveContractClient};
+use stellar_save_indexer::{
+    write_json_lines, Indexer, JsonFileSource, MemorySource, RawEvent, Store,
+};
+
+/// Converts the events of the last invocation into RPC-style records.
+struct Recorder {
+    events: Vec<RawEvent>,
+}
+
+impl Recorder {
+    fn capture(&mut self, env: &Env) {
+        let ledger = env.ledger().sequence();
+        for (contract, topics, value) in env.events().all().iter() {
+            let contract_id = match ScVal::try_from_val(env, &contract.to_val()).unwrap() {
+                ScVal::Address(address) => address.to_string(),
+                _ => unreachable!(),
+            };
+            let base64 = |val| {
+                ScVal::try_from_val(env, &val)
+                    .unwrap()
+                    .to_xdr_base64(Limits::none())
+                    .unwrap()
+            };
+            self.events.push(RawEvent {
+                id: format!("{:019}-{:010}", ledger, self.events.len() + 1),
+                ledger,
+                contract_id,
+                topic: topics.iter().map(base64).collect(),
+                value: base64(value),
+            });
+        }
+    }
+}
+
+fn strkey(env: &Env, address: &Address) -> String {
+    match ScVal::try_from_val(env, &address.to_val()).unwrap() {
+        ScVal::Address(address) => address.to_string(),
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn test_indexer_rebuilds_group_and_resumes_from_cursor() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register(StellarSaveContract, ());
+    let client = StellarSaveContractClient::new(&env, &contract_id);
+    let mut recorder = Recorder { events: Vec::new() };
+
+    let creator = Address::generate(&env);
+    let member = Address::generate(&env);
+    let member_old = Address::generate(&env);
+    let member_new = Address::generate(&env);
+    let token = env
+        .register_stellar_asset_contract_v2(creator.clone())
+        .address();
+    for address in [&creator, &member, &member_new] {
+        StellarAssetClient::new(&env, &token).mint(address, &100);
+        TokenClient::new(&env, &token).approve(address, &contract_id, &100, &1000);
+    }
+
+    let group_id = client.create_group(&creator, &100, &3600, &3, &2, &None);
+    recorder.capture(&env);
+    client.set_group_token(&group_id, &creator, &token);
+    recorder.capture(&env);
+    for address in [&creator, &member, &member_old] {
+        client.join_group(&group_id, address);
+        recorder.capture(&env);
+    }
+    client.activate_group(&group_id, &creator);
+    recorder.capture(&env);
+    let midpoint = recorder.events.len();
+
+    client.rotate_member_address(&group_id, &member_old, &member_new);
+    recorder.capture(&env);
+    for address in [&creator, &member, &member_new] {
+        client.contribute_for(&group_id, address, address);
+        recorder.capture(&env);
+    }
+    let recipient = client.get_payout_slots(&group_id).get(0).unwrap().recipient;
+    client.transfer_payout(&group_id, &creator, &recipient, &300, &0);
+    recorder.capture(&env);
+
+    // 1. Index the first half from a JSON Lines file on disk
+    let dir = std::env::temp_dir().join(format!("stellar-save-indexer-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let events_path = dir.join("events.jsonl");
+    let db_path = dir.join("index.sqlite");
+    let _ = std::fs::remove_file(&db_path);
+
+    write_json_lines(&events_path, &recorder.events[..midpoint]).unwrap();
+    let mut indexer = Indexer::new(Store::open(&db_path).unwrap()).with_page_size(2);
+    let applied = indexer.sync(&mut JsonFileSource::open(&events_path).unwrap()).unwrap();
+    assert_eq!(applied, midpoint);
+    let group = indexer.store().group(group_id).unwrap().unwrap();
+    assert_eq!(group.status, GroupStatus::Active.as_u32());
+    assert_eq!(group.member_count, 3);
+    drop(indexer);
+
+    // 2. Reopen the database and resume with the full capture
+    write_json_lines(&events_path, &recorder.events).unwrap();
+    let mut indexer = Indexer::new(Store::open(&db_path).unwrap()).with_page_size(2);
+    let applied = indexer.sync(&mut JsonFileSource::open(&events_path).unwrap()).unwrap();
+    assert_eq!(applied, recorder.events.len() - midpoint);
+    assert_eq!(
+        indexer.store().cursor().unwrap().as_ref(),
+        Some(&recorder.events.last().unwrap().id)
+    );
+    assert_eq!(indexer.sync(&mut JsonFileSource::open(&events_path).unwrap()).unwrap(), 0);
+
+    // 3. The indexed state matches the contract's
+    let store = indexer.store();
+    let onchain = client.get_group(&group_id);
+    let group = store.group(group_id).unwrap().unwrap();
+    assert_eq!(group.creator, strkey(&env, &creator));
+    assert_eq!(group.contribution_amount, 100);
+    assert_eq!(group.member_count, onchain.member_count);
+    assert_eq!(group.current_cycle, onchain.current_cycle);
+    assert_eq!(group.status, onchain.status.as_u32());
+    let payout = client.get_member_payout(&group_id, &recipient).unwrap();
+    assert_eq!(group.total_paid_out as i128, payout.amount);
+
+    let members = store.members(group_id).unwrap();
+    let current: Vec<_> = members.iter().map(|m| m.address.clone()).collect();
+    assert_eq!(
+        current,
+        vec![strkey(&env, &creator), strkey(&env, &member), strkey(&env, &member_new)]
+    );
+    assert!(members.iter().all(|m| m.total_contributed == 100 && m.left_at.is_none()));
+    let paid = members
+        .iter()
+        .find(|m| m.address == strkey(&env, &recipient))
+        .unwrap();
+    assert_eq!(paid.total_received, 300);
+
+    let contributions = store.contributions(group_id).unwrap();
+    assert_eq!(contributions.len(), 3);
+    assert!(contributions.iter().all(|c| c.cycle == 0 && c.amount == 100));
+    let payouts = store.payouts(group_id).unwrap();
+    assert_eq!(payouts.len(), 1);
+    assert_eq!(payouts[0].recipient, strkey(&env, &recipient));
+
+    // 4. A one-shot sync from an RPC stand-in ends in the same state
+    let mut rpc = MemorySource::new(recorder.events.clone());
+    let mut fresh = Indexer::new(Store::open_in_memory().unwrap());
+    fresh.sync(&mut rpc).unwrap();
+    assert_eq!(fresh.store().groups().unwrap(), store.groups().unwrap());
+    assert_eq!(fresh.store().members(group_id).unwrap(), members);
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}